@@ -1,6 +1,6 @@
 use fxhash::FxHashMap as HashMap;
 
-use shared::parse::Parsable;
+use shared::parse::swar::numbers;
 use shared::*;
 
 extern crate shared;
@@ -11,10 +11,10 @@ fn parse(input: &str) -> (Vec<u32>, Vec<u32>) {
     let mut left = Vec::new();
     let mut right = Vec::new();
 
-    for line in input.lines() {
-        let mut bytes = line.bytes();
-        left.push(bytes.next_number().unwrap());
-        right.push(bytes.next_number().unwrap());
+    let mut numbers = numbers(input.as_bytes());
+    while let Some((l, r)) = numbers.next().zip(numbers.next()) {
+        left.push(l);
+        right.push(r);
     }
 
     (left, right)
@@ -40,14 +40,9 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 11)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(2756096)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -81,13 +76,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 31)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(23117829)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
@@ -1,4 +1,4 @@
-use shared::parse::Parsable;
+use shared::parse::swar::numbers;
 use shared::*;
 
 extern crate shared;
@@ -9,9 +9,9 @@ fn solve_1(input: &str) -> usize {
     input
         .lines()
         .filter_map(|line| {
-            let mut bytes = line.bytes();
-            let mut previous: u8 = bytes.next_number().unwrap();
-            let current: u8 = bytes.next_number().unwrap();
+            let mut numbers = numbers::<u8>(line.as_bytes());
+            let mut previous = numbers.next().unwrap();
+            let current = numbers.next().unwrap();
 
             let diff = previous.abs_diff(current);
             if diff < 1 || diff > 3 {
@@ -21,7 +21,7 @@ fn solve_1(input: &str) -> usize {
 
             previous = current;
 
-            while let Some(current) = bytes.next_number() {
+            for current in numbers {
                 let diff = previous.abs_diff(current);
                 if diff < 1
                     || diff > 3
@@ -48,14 +48,9 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 2)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(236)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -63,12 +58,12 @@ fn solve_2(input: &str) -> usize {
     input
         .lines()
         .filter_map(|line| {
-            let mut bytes = line.bytes();
+            let mut numbers = numbers::<u8>(line.as_bytes()).map(i16::from);
             let mut deltas = Vec::new();
             let mut s = 0;
 
-            let mut previous: i16 = bytes.next_number().unwrap();
-            while let Some(current) = bytes.next_number() {
+            let mut previous = numbers.next().unwrap();
+            for current in numbers {
                 let d: i16 = current - previous;
                 deltas.push(d);
                 s += d.signum();
@@ -132,13 +127,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 4)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(308)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
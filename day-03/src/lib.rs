@@ -1,66 +1,30 @@
-#![feature(iter_advance_by)]
-use parse::ParsableStrict;
+use parse::cursor::Cursor;
 use shared::*;
 
 extern crate shared;
 
 pub const _INPUT: &'static str = include_str!("_input.txt");
 
-fn multiply<T: Iterator<Item = u8>>(iter: &mut T) -> Option<usize> {
-    let n = iter.next();
-    if n.is_none() {
-        return None;
-    }
-
-    let c = n.unwrap();
-    if c != b'(' {
-        return None;
-    }
-
-    let (l, n): (Option<usize>, Option<u8>) = iter.next_number_strict();
-    if l.is_none() || n.is_none() {
-        return None;
-    }
-
-    let a = l.unwrap();
-    if a >= 1000 {
-        return None;
-    }
-
-    let c = n.unwrap();
-    if c != b',' {
-        return None;
-    }
-
-    let (r, n): (Option<usize>, Option<u8>) = iter.next_number_strict();
-    if r.is_none() || n.is_none() {
-        return None;
-    }
-
-    let b = r.unwrap();
-    if b >= 1000 {
-        return None;
-    }
-
-    let c = n.unwrap();
-    if c != b')' {
-        return None;
-    }
+fn multiply(cursor: &mut Cursor) -> Option<usize> {
+    cursor.expect_literal("mul(").ok()?;
+    let a: usize = cursor.number_with_max_digits(3).ok()?;
+    cursor.expect_byte(b',').ok()?;
+    let b: usize = cursor.number_with_max_digits(3).ok()?;
+    cursor.expect_byte(b')').ok()?;
 
-    return Some(a * b);
+    Some(a * b)
 }
 
 fn solve_1(input: &str) -> usize {
     let mut s = 0;
-    for line in input.lines() {
-        let mut l = line;
-        while let Some(i) = l.find("mul") {
-            l = &l[i + 3..];
-            let mut iter = l.bytes();
-
-            if let Some(r) = multiply(&mut iter) {
-                s += r;
-            }
+    let mut cursor = Cursor::new(input.as_bytes());
+    while cursor.skip_until(|byte| byte == b'm') {
+        let checkpoint = cursor.save();
+        if let Some(r) = multiply(&mut cursor) {
+            s += r;
+        } else {
+            cursor.restore(checkpoint);
+            cursor.advance(1);
         }
     }
     s
@@ -75,44 +39,29 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 161)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(181345830)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
 fn solve_2(input: &str) -> usize {
     let mut s = 0;
     let mut enabled = true;
-    for line in input.lines() {
-        let mut l = line;
-        while let Some(i) = l.find("mul") {
-            let left_string = &l[0..i];
-            l = &l[i + 3..];
-            if let Some(d) = left_string.rfind("do") {
-                if left_string.len() >= d + 5 {
-                    if left_string[d + 2..d + 5] == *"n't" {
-                        enabled = false;
-                        continue;
-                    }
-                }
-                enabled = true;
-            }
-
-            if !enabled {
-                continue;
-            }
-
-            let mut iter = l.bytes();
-
-            if let Some(r) = multiply(&mut iter) {
+    let mut cursor = Cursor::new(input.as_bytes());
+    while cursor.skip_until(|byte| byte == b'm' || byte == b'd') {
+        let checkpoint = cursor.save();
+        if cursor.expect_literal("do()").is_ok() {
+            enabled = true;
+        } else if cursor.expect_literal("don't()").is_ok() {
+            enabled = false;
+        } else if let Some(r) = multiply(&mut cursor) {
+            if enabled {
                 s += r;
             }
+        } else {
+            cursor.restore(checkpoint);
+            cursor.advance(1);
         }
     }
     s
@@ -127,13 +76,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 48)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(98729041)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
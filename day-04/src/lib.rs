@@ -18,43 +18,23 @@ enum Letter {
 }
 
 fn parse(input: &str) -> Grid<Letter> {
-    let lines = input.lines();
-    let mut height = 0;
-    let mut vec = Vec::new();
-
-    for line in lines {
-        height += 1;
-        for char in line.bytes() {
-            match char {
-                b'X' => vec.push(Letter::X),
-                b'M' => vec.push(Letter::M),
-                b'A' => vec.push(Letter::A),
-                b'S' => vec.push(Letter::S),
-                _ => {
-                    panic!("Unexpected character")
-                }
-            }
-        }
-    }
-
-    Grid::from(vec, height)
+    Grid::parse(input, |byte, _| match byte {
+        b'X' => Some(Letter::X),
+        b'M' => Some(Letter::M),
+        b'A' => Some(Letter::A),
+        b'S' => Some(Letter::S),
+        _ => None,
+    })
+    .unwrap()
 }
 
 const LETTERS: [Letter; 3] = [Letter::M, Letter::A, Letter::S];
 
 fn test_1(map: &Grid<Letter>, point: Point, direction: Direction) -> bool {
-    let mut current = point;
-    for letter in LETTERS {
-        if let Some(next) = map.go(current, direction) {
-            if map[next] == letter {
-                current = next;
-                continue;
-            }
-        }
-        return false;
-    }
-
-    true
+    let mut ray = map.ray(point, direction);
+    LETTERS
+        .iter()
+        .all(|letter| ray.next().is_some_and(|next| map[next] == *letter))
 }
 
 fn solve_1(map: Grid<Letter>) -> usize {
@@ -82,14 +62,9 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 18)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(2543)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -148,13 +123,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 9)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(1930)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
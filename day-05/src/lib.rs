@@ -1,6 +1,4 @@
-use std::iter::from_fn;
-
-use parse::Parsable;
+use parse::sections::Sectioned;
 use shared::*;
 
 extern crate shared;
@@ -9,24 +7,13 @@ pub const _INPUT: &'static str = include_str!("_input.txt");
 
 fn parse(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
     let mut rules = vec![Vec::new(); 100];
-    let mut updates = Vec::new();
-
-    let mut line_iter = input.lines().into_iter();
+    let mut sections = input.sections();
 
-    while let Some(line) = line_iter.next() {
-        if line.is_empty() {
-            break;
-        }
-        let mut bytes = line.bytes();
-        let x: usize = bytes.next_number().unwrap();
-        let y: usize = bytes.next_number().unwrap();
+    for (x, y) in sections.next().unwrap().lines_of::<(usize, usize)>() {
         rules[x].push(y);
     }
 
-    while let Some(line) = line_iter.next() {
-        let mut bytes = line.bytes();
-        updates.push(from_fn(|| bytes.next_number()).collect());
-    }
+    let updates = sections.next().unwrap().lines_of().collect();
 
     (rules, updates)
 }
@@ -55,14 +42,9 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 143)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(5452)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -113,13 +95,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 123)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(4598)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use grid::Grid;
+use grid::{bits::BitGrid, ray::Blockers, Grid};
 use points::{directions::Direction, point::Point};
 use shared::*;
 
@@ -15,51 +15,36 @@ enum Tile {
 }
 
 fn parse(input: &str) -> (Grid<Tile>, Point) {
-    let mut vec = Vec::new();
-
-    let mut height = 0;
-    let mut start = Point::new(0, 0);
-
-    for line in input.lines() {
-        for (x, byte) in line.bytes().enumerate() {
-            match byte {
-                b'.' => vec.push(Tile::Empty),
-                b'#' => vec.push(Tile::Obstacle),
-                b'^' => {
-                    vec.push(Tile::Empty);
-                    start.x = x;
-                    start.y = height;
-                }
-                _ => panic!("Unexpected input"),
-            }
-        }
-
-        height += 1;
-    }
-
-    (Grid::from(vec, height), start)
+    let (grid, markers) = Grid::parse_with_markers(input, b"^", |byte, _| match byte {
+        b'.' | b'^' => Some(Tile::Empty),
+        b'#' => Some(Tile::Obstacle),
+        _ => None,
+    })
+    .unwrap();
+
+    (grid, markers[&b'^'][0])
 }
 
 fn solve_1(map: Grid<Tile>, start: Point) -> usize {
-    let mut visited = Grid::from(vec![false; map.width * map.height], map.height);
+    let mut visited = BitGrid::filled(false, map.height, map.width);
     let mut current = start;
-    let mut visited_count = 1;
     let mut direction = Direction::North;
-    visited[current] = true;
-
-    while let Some(next) = map.go(current, direction) {
-        if map[next] == Tile::Obstacle {
-            direction = direction.rotate_90();
-            continue;
-        } else if !visited[next] {
-            visited[next] = true;
-            visited_count += 1;
+    visited.insert(current);
+
+    loop {
+        for next in map
+            .ray(current, direction)
+            .take_while(|next| map[*next] == Tile::Empty)
+        {
+            visited.insert(next);
+            current = next;
         }
 
-        current = next;
+        if map.go(current, direction).is_none() {
+            return visited.count();
+        }
+        direction = direction.rotate_90();
     }
-
-    visited_count
 }
 
 pub fn part_1(_input: &str) -> Solution {
@@ -72,23 +57,34 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 41)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(5101)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
+// Jumps from obstacle to obstacle, so a loop shows up as arriving at the same one the same way.
+fn loops_with(blockers: &Blockers, start: Point, direction: Direction, obstacle: Point) -> bool {
+    let mut seen = HashSet::new();
+    let (mut current, mut direction) = (start, direction);
+    while let Some(stop) = blockers.next_with(current, direction, obstacle) {
+        current = stop.go(direction.reverse());
+        if !seen.insert((current, direction as usize)) {
+            return true;
+        }
+        direction = direction.rotate_90();
+    }
+
+    false
+}
+
 fn solve_2(map: Grid<Tile>, start: Point) -> usize {
-    let mut visited = Grid::from(vec![[false; 4]; map.width * map.height], map.height);
+    let blockers = map.blockers(|tile| *tile == Tile::Obstacle);
+    let mut tried = BitGrid::filled(false, map.height, map.width);
     let mut current = start;
-    let mut loops = HashSet::new();
+    let mut loops = 0;
     let mut direction = Direction::North;
-    visited[current][0] = true;
+    tried.insert(current);
 
     while let Some(next) = map.go(current, direction) {
         if map[next] == Tile::Obstacle {
@@ -96,32 +92,15 @@ fn solve_2(map: Grid<Tile>, start: Point) -> usize {
             continue;
         }
 
-        if visited[next].iter().all(|seen| !seen) {
-            let mut visited_b = Grid::from(vec![[false; 4]; map.width * map.height], map.height);
-            let mut direction_b = direction.rotate_90();
-            let mut current_b = current;
-
-            while let Some(next_b) = map.go(current_b, direction_b) {
-                if map[next_b] == Tile::Obstacle || next_b == next {
-                    direction_b = direction_b.rotate_90();
-                    continue;
-                } else if visited[next_b][direction_b as usize]
-                    || visited_b[next_b][direction_b as usize]
-                {
-                    loops.insert(next);
-                    break;
-                }
-
-                visited_b[next_b][direction_b as usize] = true;
-                current_b = next_b;
-            }
+        // An obstacle can only go where the guard hasn't been yet, or the path there would change.
+        if tried.insert(next) && loops_with(&blockers, current, direction, next) {
+            loops += 1;
         }
 
-        visited[next][direction as usize] = true;
         current = next;
     }
 
-    loops.len()
+    loops
 }
 
 pub fn part_2(_input: &str) -> Solution {
@@ -136,16 +115,13 @@ mod part_2_tests {
 
     #[test_case(include_str!("_edge.txt"), 4)]
     fn edge_case_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
-    #[test_case(include_str!("_test.txt"), 6)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
+        assert_eq!(part_2(&examples::normalized(input)), expected.into());
     }
 
     #[test_case(1951)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
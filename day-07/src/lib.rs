@@ -67,14 +67,9 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 3749)]
-    fn example_input(input: &str, expected: u64) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(4364915411363)]
     fn real_input(expected: u64) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -134,18 +129,15 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 11387)]
-    fn example_input(input: &str, expected: u64) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(include_str!("_edge.txt"), 0)]
     fn edge_input(input: &str, expected: u64) {
-        assert_eq!(part_2(input), expected.into());
+        assert_eq!(part_2(&examples::normalized(input)), expected.into());
     }
 
     #[test_case(38322057216320)]
     fn real_input(expected: u64) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
@@ -1,6 +1,6 @@
 use fxhash::FxHashMap as HashMap;
 
-use grid::Grid;
+use grid::bits::BitGrid;
 use points::ipoint::IPoint;
 use points::point::Point;
 use shared::*;
@@ -40,8 +40,7 @@ fn parse(input: &str) -> (Vec<Vec<IPoint>>, usize, usize) {
 
 pub fn part_1(_input: &str) -> Solution {
     let (sets, width, height) = parse(_input);
-    let mut map = Grid::from(vec![false; width * height], height);
-    let mut antinodes: usize = 0;
+    let mut map = BitGrid::filled(false, height, width);
 
     for set in sets {
         for i in 0..(set.len() - 1) {
@@ -53,20 +52,14 @@ pub fn part_1(_input: &str) -> Solution {
                     if let Some(antinode) = other.checked_sub(offset) {
                         if let Ok(point) = Point::try_from(antinode) {
                             if map.is_within_bounds(point) {
-                                if !map[point] {
-                                    map.insert(point, true);
-                                    antinodes += 1;
-                                }
+                                map.insert(point);
                             }
                         }
                     }
                     if let Some(antinode) = current.checked_add(offset) {
                         if let Ok(point) = Point::try_from(antinode) {
                             if map.is_within_bounds(point) {
-                                if !map[point] {
-                                    map.insert(point, true);
-                                    antinodes += 1;
-                                }
+                                map.insert(point);
                             }
                         }
                     }
@@ -75,7 +68,7 @@ pub fn part_1(_input: &str) -> Solution {
         }
     }
 
-    antinodes.into()
+    map.count().into()
 }
 
 #[cfg(test)]
@@ -83,80 +76,45 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 14)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(293)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
 pub fn part_2(_input: &str) -> Solution {
     let (sets, width, height) = parse(_input);
-    let mut map = Grid::from(vec![false; width * height], height);
-    let mut antinodes: usize = 0;
+    let mut map = BitGrid::filled(false, height, width);
 
     for set in sets {
         for i in 0..(set.len() - 1) {
             let current = set[i];
             if let Ok(current_point) = Point::try_from(current) {
-                if !map[current_point] {
-                    map.insert(current_point, true);
-                    antinodes += 1;
-                }
+                map.insert(current_point);
             }
 
             for n in (i + 1)..set.len() {
                 let other = set[n];
                 if let Ok(other_point) = Point::try_from(other) {
-                    if !map[other_point] {
-                        map.insert(other_point, true);
-                        antinodes += 1;
-                    }
+                    map.insert(other_point);
                 }
 
-                if let Some(offset) = current.checked_sub(other) {
-                    let mut point = current;
-                    loop {
-                        if let Some(antinode) = point.checked_add(offset) {
-                            if let Ok(antinode_point) = Point::try_from(antinode) {
-                                if map.is_within_bounds(antinode_point) {
-                                    if !map[antinode_point] {
-                                        map.insert(antinode_point, true);
-                                        antinodes += 1;
-                                    }
-                                    point = antinode;
-                                    continue;
-                                }
-                            }
-                        }
-                        break;
-                    }
-                    point = other;
-                    loop {
-                        if let Some(antinode) = point.checked_sub(offset) {
-                            if let Ok(antinode_point) = Point::try_from(antinode) {
-                                if map.is_within_bounds(antinode_point) {
-                                    if !map[antinode_point] {
-                                        map.insert(antinode_point, true);
-                                        antinodes += 1;
-                                    }
-                                    point = antinode;
-                                    continue;
-                                }
-                            }
-                        }
-                        break;
+                if let (Some(offset), Ok(current), Ok(other)) = (
+                    current.checked_sub(other),
+                    Point::try_from(current),
+                    Point::try_from(other),
+                ) {
+                    let outwards = map.ray(current, offset);
+                    let inwards = map.ray(other, offset * -1);
+                    for antinode in outwards.chain(inwards) {
+                        map.insert(antinode);
                     }
                 }
             }
         }
     }
 
-    antinodes.into()
+    map.count().into()
 }
 
 #[cfg(test)]
@@ -164,13 +122,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 34)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(934)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
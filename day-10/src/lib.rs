@@ -1,4 +1,5 @@
-use grid::Grid;
+use grid::{bits::BitGrid, Grid};
+use parse::ToDigit;
 use points::{directions::Direction, point::Point};
 use shared::*;
 
@@ -7,23 +8,10 @@ extern crate shared;
 pub const _INPUT: &'static str = include_str!("_input.txt");
 
 fn parse(input: &str) -> Grid<u8> {
-    let mut vec = Vec::new();
-    let mut height = 0;
-
-    for line in input.lines() {
-        for byte in line.bytes() {
-            if !byte.is_ascii_digit() {
-                panic!("unexpected input");
-            }
-            vec.push(byte - b'0');
-        }
-        height += 1;
-    }
-
-    Grid::from(vec, height)
+    Grid::parse(input, |byte, _| byte.to_digit()).unwrap()
 }
 
-fn traverse_1<I>(map: &Grid<u8>, iter: I, height: u8, seen: &mut Grid<bool>) -> usize
+fn traverse_1<I>(map: &Grid<u8>, iter: I, height: u8, seen: &mut BitGrid) -> usize
 where
     I: Iterator<Item = (Point, Direction)>,
 {
@@ -31,8 +19,7 @@ where
     let valid_points = iter.filter(|(p, _)| map[*p] == height);
     if height >= 9 {
         for (p, _) in valid_points {
-            if !seen[p] {
-                seen[p] = true;
+            if seen.insert(p) {
                 result += 1;
             }
         }
@@ -54,7 +41,7 @@ pub fn part_1(_input: &str) -> Solution {
     map.points()
         .filter_map(|position| {
             if map[position] == 0 {
-                let mut seen = Grid::from(vec![false; map.height * map.width], map.height);
+                let mut seen = BitGrid::filled(false, map.height, map.width);
                 return Some(traverse_1(
                     &map,
                     map.adjacent_four_directional(position),
@@ -73,18 +60,9 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test_1.txt"), 1)]
-    #[test_case(include_str!("_test_2.txt"), 2)]
-    #[test_case(include_str!("_test_3.txt"), 3)]
-    #[test_case(include_str!("_test_4.txt"), 4)]
-    #[test_case(include_str!("_test_36.txt"), 36)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(617)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -126,13 +104,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test_36.txt"), 81)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(1477)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
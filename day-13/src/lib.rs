@@ -1,23 +1,29 @@
-use parse::Parsable;
+use parse::sections::Sectioned;
 use points::ipoint::IPoint;
 use shared::*;
-use std::iter::from_fn;
 
 extern crate shared;
 
 pub const _INPUT: &'static str = include_str!("_input.txt");
 
 fn parse(input: &str) -> Vec<(IPoint, IPoint, IPoint)> {
-    let mut bytes = input.bytes();
-
-    from_fn(|| {
-        bytes
-            .next_number()
-            .zip(bytes.next_number())
-            .zip(bytes.next_number())
-    })
-    .map(|((a, b), c)| (a, b, c))
-    .collect()
+    input
+        .sections()
+        .map(|machine| {
+            let (ax, ay, bx, by, x, y) = scan!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+                machine,
+                isize,
+                isize,
+                isize,
+                isize,
+                isize,
+                isize
+            )
+            .unwrap();
+            (IPoint::new(ax, ay), IPoint::new(bx, by), IPoint::new(x, y))
+        })
+        .collect()
 }
 
 fn check_single(vec: &IPoint, c: &IPoint) -> Option<isize> {
@@ -74,14 +80,9 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 480)]
-    fn example_input(input: &str, expected: isize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(28262)]
     fn real_input(expected: isize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -100,13 +101,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 875318608908)]
-    fn example_input(input: &str, expected: isize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(101406661266314)]
     fn real_input(expected: isize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
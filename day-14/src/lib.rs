@@ -1,11 +1,9 @@
 use math::solve_linear_diophantine;
-use parse::Parsable;
 use points::{
     ipoint::IPoint,
     traits::{Absolute, ModuloPositive},
 };
 use shared::*;
-use std::iter::from_fn;
 
 extern crate shared;
 
@@ -17,9 +15,12 @@ struct Robot {
 }
 
 fn parse(input: &str) -> Vec<Robot> {
-    let mut bytes = input.bytes();
-    from_fn(|| bytes.next_number().zip(bytes.next_number()))
-        .map(|(p, v)| Robot { p, v })
+    input
+        .lines()
+        .map(|line| {
+            let (p, v) = scan!("p={} v={}", line, IPoint, IPoint).unwrap();
+            Robot { p, v }
+        })
         .collect()
 }
 
@@ -57,12 +58,15 @@ mod part_1_tests {
 
     #[test_case(include_str!("_test.txt"), 12)]
     fn example_input(input: &str, expected: usize) {
-        assert_eq!(solve_1(input, 11, 7), expected.into());
+        assert_eq!(
+            solve_1(&examples::normalized(input), 11, 7),
+            expected.into()
+        );
     }
 
     #[test_case(218619120)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -118,6 +122,6 @@ mod part_2_tests {
 
     #[test_case(7055)]
     fn real_input(expected: isize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
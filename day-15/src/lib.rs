@@ -1,4 +1,5 @@
 use grid::Grid;
+use parse::sections::Sectioned;
 use points::{directions::Direction, point::Point};
 use shared::*;
 
@@ -30,34 +31,18 @@ impl std::fmt::Debug for Tile {
 }
 
 fn parse(input: &str) -> (Grid<Tile>, Point, Vec<Direction>) {
-    let mut vec = Vec::new();
-    let mut y = 0;
-    let mut start = Point::new(0, 0);
-    let mut directions = Vec::new();
-
-    let mut lines = input.lines();
-
-    while let Some(line) = lines.next() {
-        if line.is_empty() {
-            break;
-        }
-
-        for (x, byte) in line.bytes().enumerate() {
-            if byte == b'@' {
-                start = Point::new(x, y);
-            }
-
-            vec.push(match byte {
-                b'#' => Tile::Wall,
-                b'O' => Tile::Box,
-                _ => Tile::Empty,
-            });
-        }
-
-        y += 1;
-    }
+    let mut sections = input.sections();
+    let (map, markers) =
+        Grid::parse_with_markers(sections.next().unwrap(), b"@", |byte, _| match byte {
+            b'#' => Some(Tile::Wall),
+            b'O' => Some(Tile::Box),
+            b'.' | b'@' => Some(Tile::Empty),
+            _ => None,
+        })
+        .unwrap();
 
-    while let Some(line) = lines.next() {
+    let mut directions = Vec::new();
+    for line in sections.next().unwrap().lines() {
         for byte in line.bytes() {
             directions.push(match byte {
                 b'^' => Direction::North,
@@ -69,7 +54,7 @@ fn parse(input: &str) -> (Grid<Tile>, Point, Vec<Direction>) {
         }
     }
 
-    (Grid::from(vec, y), start, directions)
+    (map, markers[&b'@'][0], directions)
 }
 
 fn move_small_boxes(map: &mut Grid<Tile>, point: Point, direction: Direction) -> Point {
@@ -124,39 +109,18 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test_1.txt"), 2028)]
-    #[test_case(include_str!("_test_2.txt"), 10092)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(0)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
 fn expand_map(map: Grid<Tile>) -> Grid<Tile> {
-    let mut vec = Vec::with_capacity(map.height * map.width * 2);
-    for tile in map.vec {
-        match tile {
-            Tile::Empty => {
-                vec.push(Tile::Empty);
-                vec.push(Tile::Empty);
-            }
-            Tile::Wall => {
-                vec.push(Tile::Wall);
-                vec.push(Tile::Wall);
-            }
-            Tile::Box => {
-                vec.push(Tile::Box);
-                vec.push(Tile::BoxRight);
-            }
-            _ => panic!("Unexpected right box in unexpanded map"),
-        }
-    }
-
-    Grid::from(vec, map.height)
+    map.stretch(2, 1, |tile, offset| match (tile, offset.x) {
+        (Tile::Box, 1) => Tile::BoxRight,
+        (Tile::BoxRight, _) => panic!("Unexpected right box in unexpanded map"),
+        _ => *tile,
+    })
 }
 
 fn move_next_box(map: &mut Grid<Tile>, from: Point, to: Point) {
@@ -274,13 +238,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test_2.txt"), 9021)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(1463160)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
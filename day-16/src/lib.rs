@@ -1,4 +1,6 @@
-use grid::Grid;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use grid::{bits::BitGrid, Grid};
 use points::{
     directions::{Direction, CARDINALS},
     point::Point,
@@ -10,65 +12,66 @@ extern crate shared;
 pub const _INPUT: &'static str = include_str!("_input.txt");
 
 fn parse(input: &str) -> (Grid<bool>, Point, Point) {
-    let mut vec = Vec::new();
-    let mut y = 0;
-
-    let mut start = Point::new(0, 0);
-    let mut end = Point::new(0, 0);
-
-    for line in input.lines() {
-        for (x, byte) in line.bytes().enumerate() {
-            match byte {
-                b'#' => vec.push(false),
-                b'.' => vec.push(true),
-                b'S' => {
-                    vec.push(true);
-                    start = Point::new(x, y);
-                }
-                b'E' => {
-                    vec.push(true);
-                    end = Point::new(x, y);
-                }
-                _ => panic!("Unexpected input"),
-            }
-        }
-        y += 1;
-    }
+    let (grid, markers) = Grid::parse_with_markers(input, b"SE", |byte, _| match byte {
+        b'#' => Some(false),
+        b'.' | b'S' | b'E' => Some(true),
+        _ => None,
+    })
+    .unwrap();
+
+    (grid, markers[&b'S'][0], markers[&b'E'][0])
+}
 
-    return (Grid::from(vec, y), start, end);
+// Turning only pays off when it's followed by a step, so both go in one move. Turning round only
+// pays off at the start, anywhere else it undoes the step that led there and turning a step
+// earlier is cheaper.
+fn moves(
+    map: &Grid<bool>,
+    start: Point,
+    &(point, direction): &(Point, Direction),
+) -> [Option<((Point, Direction), usize)>; 4] {
+    let step = |direction: Direction, cost| {
+        let ahead = point.go(direction);
+        map[ahead].then_some(((ahead, direction), cost))
+    };
+    [
+        step(direction, 1),
+        step(direction.rotate_counter_90(), 1001),
+        step(direction.rotate_90(), 1001),
+        (point == start)
+            .then(|| step(direction.reverse(), 2001))
+            .flatten(),
+    ]
+}
+
+// Undoes `moves`: the step into `point` came from behind it, facing any way beforehand.
+fn unmoves(
+    map: &Grid<bool>,
+    start: Point,
+    &(point, direction): &(Point, Direction),
+) -> [Option<((Point, Direction), usize)>; 4] {
+    let behind = point.go(direction.reverse());
+    if !map[behind] {
+        return [None; 4];
+    }
+    [
+        Some(((behind, direction), 1)),
+        Some(((behind, direction.rotate_90()), 1001)),
+        Some(((behind, direction.rotate_counter_90()), 1001)),
+        (behind == start).then_some(((behind, direction.reverse()), 2001)),
+    ]
 }
 
 pub fn part_1(_input: &str) -> Solution {
     let (map, start, end) = parse(_input);
-    let mut costs = map.same_size_with([usize::MAX; 4]);
-    let mut buckets: Vec<Vec<(Point, Direction, usize)>> = vec![Vec::new(); 1001];
-    let mut bucket = 0;
-    buckets[0].push((start, Direction::East, 0));
-    costs[start][Direction::East as usize] = 0;
-
-    loop {
-        while let Some((point, direction, cost)) = buckets[bucket % 1001].pop() {
-            if point == end {
-                return cost.into();
-            }
-
-            let options = [
-                (map.go_if_true(point, direction), direction, cost + 1),
-                (Some(point), direction.rotate_counter_90(), cost + 1000),
-                (Some(point), direction.rotate_90(), cost + 1000),
-            ];
-
-            for (point_option, direction, cost) in options {
-                if let Some(point) = point_option {
-                    if cost < costs[point][direction as usize] {
-                        costs[point][direction as usize] = cost;
-                        buckets[cost % 1001].push((point, direction, cost));
-                    }
-                }
-            }
-        }
-        bucket += 1;
-    }
+    let search = search::dijkstra(
+        [(start, Direction::East)],
+        map.directed_indexer(),
+        |state| moves(&map, start, state),
+        |&(point, _)| point == end,
+    );
+
+    search.goal_cost().map_or(Solution::None, Solution::from)
 }
 
 #[cfg(test)]
@@ -76,59 +79,83 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test_1.txt"), 7036)]
-    #[test_case(include_str!("_test_2.txt"), 11048)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(102460)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
 pub fn part_2(_input: &str) -> Solution {
+    let (map, start, end) = parse(_input);
+    let search = search::dijkstra(
+        [(start, Direction::East)],
+        map.directed_indexer(),
+        |state| moves(&map, start, state),
+        |&(point, _)| point == end,
+    );
+    let Some(lowest) = search.goal_cost() else {
+        return Solution::None;
+    };
+
+    // The end can be reached facing any way, each of those that's as cheap counts.
+    let ends = CARDINALS
+        .map(|direction| (end, direction))
+        .into_iter()
+        .filter(|state| search.cost(state) == Some(lowest));
+    let dag = search.dag(ends, |state| unmoves(&map, start, state));
+
+    let mut seats = BitGrid::filled(false, map.height, map.width);
+    for (point, _) in dag.states() {
+        seats.insert(point);
+    }
+    seats.count().into()
+}
+
+pub fn part_2_binary_heap(_input: &str) -> Solution {
     let (map, start, end) = parse(_input);
     let mut costs = map.same_size_with([usize::MAX; 4]);
-    let mut buckets: Vec<Vec<(Point, Direction, usize)>> = vec![Vec::new(); 1001];
-    let mut bucket = 0;
-    buckets[0].push((start, Direction::East, 0));
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0, start, Direction::East as usize)));
     costs[start][Direction::East as usize] = 0;
     let mut lowest: Option<usize> = None;
 
-    'traversal: loop {
-        while let Some((point, direction, cost)) = buckets[bucket % 1001].pop() {
-            if point == end {
-                if let Some(lowest) = lowest {
-                    if cost > lowest {
-                        break 'traversal;
-                    }
-                } else {
-                    lowest = Some(cost);
+    while let Some(Reverse((cost, point, direction))) = heap.pop() {
+        if point == end {
+            if let Some(lowest) = lowest {
+                if cost > lowest {
+                    break;
                 }
+            } else {
+                lowest = Some(cost);
             }
+        }
 
-            let options = [
-                (point.go(direction), direction, cost + 1),
-                (point, direction.rotate_counter_90(), cost + 1000),
-                (point, direction.rotate_90(), cost + 1000),
-            ];
-
-            for (point, direction, cost) in options {
-                if map[point] {
-                    if cost < costs[point][direction as usize] {
-                        costs[point][direction as usize] = cost;
-                        buckets[cost % 1001].push((point, direction, cost));
-                    }
-                }
+        let direction = CARDINALS[direction];
+        let options = [
+            (point.go(direction), direction, cost + 1),
+            (point, direction.rotate_counter_90(), cost + 1000),
+            (point, direction.rotate_90(), cost + 1000),
+        ];
+
+        for (point, direction, cost) in options {
+            if map[point] && cost < costs[point][direction as usize] {
+                costs[point][direction as usize] = cost;
+                heap.push(Reverse((cost, point, direction as usize)));
             }
         }
-        bucket += 1;
     }
 
-    let mut visited = map.same_size_with(false);
-    visited[end] = true;
+    count_best_seats(&map, &costs, end, lowest).into()
+}
+
+fn count_best_seats(
+    map: &Grid<bool>,
+    costs: &Grid<[usize; 4]>,
+    end: Point,
+    lowest: Option<usize>,
+) -> usize {
+    let mut visited = BitGrid::filled(false, map.height, map.width);
+    visited.insert(end);
     if let Some(lowest) = lowest {
         let mut queue: Vec<(Point, Direction, usize)> = Vec::new();
         for direction in CARDINALS {
@@ -138,16 +165,21 @@ pub fn part_2(_input: &str) -> Solution {
         }
 
         while let Some((point, direction, remaining)) = queue.pop() {
+            // Nothing comes before the start, where there's no cost left to take off.
+            let (step, turn) = (remaining.checked_sub(1), remaining.checked_sub(1000));
             let options = [
-                (point.go(direction.reverse()), direction, remaining - 1),
-                (point, direction.rotate_counter_90(), remaining - 1000),
-                (point, direction.rotate_90(), remaining - 1000),
+                (point.go(direction.reverse()), direction, step),
+                (point, direction.rotate_counter_90(), turn),
+                (point, direction.rotate_90(), turn),
             ];
 
             for (point, direction, remaining) in options {
+                let Some(remaining) = remaining else {
+                    continue;
+                };
                 if map[point] {
                     if costs[point][direction as usize] == remaining {
-                        visited[point] = true;
+                        visited.insert(point);
                         queue.push((point, direction, remaining));
                     }
                 }
@@ -155,7 +187,7 @@ pub fn part_2(_input: &str) -> Solution {
         }
     }
 
-    visited.vec.iter().filter(|&p| *p).count().into()
+    visited.count()
 }
 
 #[cfg(test)]
@@ -163,15 +195,18 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test_1.txt"), 45)]
-    #[test_case(include_str!("_test_2.txt"), 64)]
-    #[test_case(include_str!("_test_4.txt"), 12)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
+    #[test_case(527)]
+    fn real_input(expected: usize) {
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 
     #[test_case(527)]
-    fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+    fn real_input_binary_heap(expected: usize) {
+        assert_eq!(
+            part_2_binary_heap(&examples::normalized(_INPUT)),
+            expected.into()
+        );
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2, part_2_binary_heap => 2);
@@ -1,6 +1,4 @@
-use std::iter::from_fn;
-
-use parse::Parsable;
+use parse::cursor::Cursor;
 use shared::*;
 
 extern crate shared;
@@ -8,12 +6,25 @@ extern crate shared;
 pub const _INPUT: &'static str = include_str!("_input.txt");
 
 fn parse(input: &str) -> (usize, usize, usize, Vec<usize>) {
-    let mut bytes = input.bytes();
-    let a = bytes.next_number().unwrap();
-    let b = bytes.next_number().unwrap();
-    let c = bytes.next_number().unwrap();
-    let program: Vec<usize> = from_fn(|| bytes.next_number()).collect();
+    let mut cursor = Cursor::new(input.as_bytes());
+    let mut registers = [0; 3];
+    for (register, label) in
+        registers
+            .iter_mut()
+            .zip(["Register A: ", "Register B: ", "Register C: "])
+    {
+        cursor.expect_literal(label).unwrap();
+        *register = cursor.number().unwrap();
+        cursor.expect_byte(b'\n').unwrap();
+    }
+
+    cursor.expect_literal("\nProgram: ").unwrap();
+    let mut program = vec![cursor.number().unwrap()];
+    while cursor.expect_byte(b',').is_ok() {
+        program.push(cursor.number().unwrap());
+    }
 
+    let [a, b, c] = registers;
     (a, b, c, program)
 }
 
@@ -106,7 +117,7 @@ mod part_1_tests {
         expected_c: Option<usize>,
         expected_output: &str,
     ) {
-        let (mut a, mut b, mut c, program) = parse(input);
+        let (mut a, mut b, mut c, program) = parse(&examples::normalized(input));
         let output = run(&mut a, &mut b, &mut c, &program);
 
         assert_eq!(output, expected_output.to_string());
@@ -123,7 +134,7 @@ mod part_1_tests {
 
     #[test_case("4,1,5,3,1,5,3,5,7")]
     fn real_input(expected: &str) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -166,6 +177,6 @@ mod part_2_tests {
 
     #[test_case(164542125272765)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
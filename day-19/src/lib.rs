@@ -1,5 +1,4 @@
-use std::{iter::from_fn, ops::IndexMut};
-
+use parse::{cursor::Cursor, sections::Sectioned};
 use shared::*;
 
 extern crate shared;
@@ -21,63 +20,43 @@ impl Node {
     }
 }
 
-fn parse_pattern<T: Iterator<Item = u8>>(bytes: &mut T, current: &mut Node) {
-    if let Some(i) = parse_colour(bytes) {
-        if current.children[i as usize].is_none() {
-            current.children[i as usize] = Some(Node::new());
-        }
-
-        if let Some(child) = &mut current.children.index_mut(i as usize) {
-            parse_pattern(bytes, child);
-        }
-    } else {
-        current.end = true;
+fn colour(byte: u8) -> Option<u8> {
+    match byte {
+        b'w' => Some(0),
+        b'u' => Some(1),
+        b'b' => Some(2),
+        b'r' => Some(3),
+        b'g' => Some(4),
+        _ => None,
     }
 }
 
-fn parse_colour<T: Iterator<Item = u8>>(bytes: &mut T) -> Option<u8> {
-    if let Some(b) = bytes.next() {
-        if b.is_ascii_alphabetic() {
-            return match b {
-                b'w' => Some(0),
-                b'u' => Some(1),
-                b'b' => Some(2),
-                b'r' => Some(3),
-                b'g' => Some(4),
-                _ => None,
-            };
-        }
+fn insert(root: &mut Node, pattern: &[u8]) {
+    let mut node = root;
+    for index in pattern.iter().map_while(|byte| colour(*byte)) {
+        node = node.children[index as usize].get_or_insert_with(Node::new);
     }
-    None
+    node.end = true;
 }
 
 fn parse(input: &str) -> (Node, Vec<Vec<u8>>) {
-    let mut lines = input.lines();
+    let mut sections = input.sections();
 
     let mut root = Node::new();
-
-    let mut bytes = lines.next().unwrap().bytes();
-    loop {
-        parse_pattern(&mut bytes, &mut root);
-        if let Some(_) = bytes.next() {
-            continue;
-        }
-        break;
+    let mut cursor = Cursor::new(sections.next().unwrap().as_bytes());
+    while cursor.skip_until(|byte| byte.is_ascii_alphabetic()) {
+        insert(
+            &mut root,
+            cursor.take_while(|byte| byte.is_ascii_alphabetic()),
+        );
     }
 
-    lines.next();
-
-    let designs = from_fn(|| {
-        lines.next().and_then(|l| {
-            let mut bytes = l.bytes();
-            let mut v = Vec::new();
-            while let Some(c) = parse_colour(&mut bytes) {
-                v.push(c);
-            }
-            Some(v)
-        })
-    })
-    .collect();
+    let designs = sections
+        .next()
+        .unwrap()
+        .lines()
+        .map(|line| line.bytes().map_while(colour).collect())
+        .collect();
 
     (root, designs)
 }
@@ -131,14 +110,9 @@ mod part_1_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 6)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
-    }
-
     #[test_case(358)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -194,13 +168,10 @@ mod part_2_tests {
     use crate::*;
     use test_case::test_case;
 
-    #[test_case(include_str!("_test.txt"), 16)]
-    fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
-    }
-
     #[test_case(600639829400603)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
+
+example_tests!(part_1 => 1, part_2 => 2);
@@ -7,31 +7,14 @@ extern crate shared;
 pub const _INPUT: &'static str = include_str!("_input.txt");
 
 fn parse(input: &str) -> (Grid<bool>, Point, Point) {
-    let mut vec = Vec::new();
-    let mut start = Point::new(0, 0);
-    let mut end = Point::new(0, 0);
-    let mut y = 0;
-
-    for line in input.lines() {
-        for (x, byte) in line.bytes().enumerate() {
-            match byte {
-                b'#' => vec.push(false),
-                b'.' => vec.push(true),
-                b'S' => {
-                    vec.push(true);
-                    start = Point::new(x, y);
-                }
-                b'E' => {
-                    vec.push(true);
-                    end = Point::new(x, y);
-                }
-                _ => panic!("Unexpected input"),
-            }
-        }
-        y += 1;
-    }
-
-    (Grid::from(vec, y), start, end)
+    let (grid, markers) = Grid::parse_with_markers(input, b"SE", |byte, _| match byte {
+        b'#' => Some(false),
+        b'.' | b'S' | b'E' => Some(true),
+        _ => None,
+    })
+    .unwrap();
+
+    (grid, markers[&b'S'][0], markers[&b'E'][0])
 }
 
 fn map_costs(
@@ -127,12 +110,12 @@ mod part_1_tests {
 
     #[test_case(include_str!("_test.txt"), 2)]
     fn example_input(input: &str, expected: usize) {
-        assert_eq!(solve(input, 40, 2), expected.into())
+        assert_eq!(solve(&examples::normalized(input), 40, 2), expected.into())
     }
 
     #[test_case(1511)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -147,11 +130,11 @@ mod part_2_tests {
 
     #[test_case(include_str!("_test.txt"), 29)]
     fn example_input(input: &str, expected: usize) {
-        assert_eq!(solve(input, 72, 20), expected.into())
+        assert_eq!(solve(&examples::normalized(input), 72, 20), expected.into())
     }
 
     #[test_case(1020507)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
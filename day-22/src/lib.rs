@@ -15,12 +15,12 @@ mod part_1_tests {
 
     #[test_case(include_str!("_test.txt"), 2)]
     fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_1(input), expected.into());
+        assert_eq!(part_1(&examples::normalized(input)), expected.into());
     }
 
     #[test_case(0)]
     fn real_input(expected: usize) {
-        assert_eq!(part_1(_INPUT), expected.into());
+        assert_eq!(part_1(&examples::normalized(_INPUT)), expected.into());
     }
 }
 
@@ -35,11 +35,11 @@ mod part_2_tests {
 
     #[test_case(include_str!("_test.txt"), 47)]
     fn example_input(input: &str, expected: usize) {
-        assert_eq!(part_2(input), expected.into());
+        assert_eq!(part_2(&examples::normalized(input)), expected.into());
     }
 
     #[test_case(0)]
     fn real_input(expected: usize) {
-        assert_eq!(part_2(_INPUT), expected.into());
+        assert_eq!(part_2(&examples::normalized(_INPUT)), expected.into());
     }
 }
@@ -1,14 +1,20 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, PlotConfiguration};
-use runner::day;
-use shared::{day_name, Solution};
+use runner::{day, variants, PARTS};
+use shared::{day_name, parse::normalize::normalize, Solution};
 
 extern crate shared;
 
+// Inputs are normalized the way the runner does it before they reach a day, outside the timing.
 fn criterion_benchmark(c: &mut Criterion) {
+    let days: Vec<_> = (1..=PARTS)
+        .map(|i| {
+            let (function, input, _) = day(i);
+            (function, normalize(input).0)
+        })
+        .collect();
     c.bench_function("Total: All Days", |b| {
         b.iter(|| {
-            for i in 1..=49 {
-                let (function, input, _) = day(i);
+            for (function, input) in &days {
                 function(black_box(input));
             }
         })
@@ -18,11 +24,13 @@ fn criterion_benchmark(c: &mut Criterion) {
     group
         .plot_config(PlotConfiguration::default().summary_scale(criterion::AxisScale::Logarithmic));
 
-    for i in 1..=49 {
+    for i in 1..=PARTS {
         let (function, input, name) = day(i);
+        let (input, _) = normalize(input);
+        let input = input.as_ref();
         if let Solution::None = function(input) {
         } else {
-            let title = day_name((i + 1) / 2);
+            let title = day_name(i.div_ceil(2));
             group.bench_function(&format!("{}: {}", name, title), |b| {
                 b.iter(|| function(black_box(input)))
             });
@@ -30,6 +38,23 @@ fn criterion_benchmark(c: &mut Criterion) {
     }
 
     group.finish();
+
+    for i in 1..=PARTS {
+        let alternatives = variants(i);
+        if alternatives.is_empty() {
+            continue;
+        }
+
+        let (function, input, name) = day(i);
+        let (input, _) = normalize(input);
+        let input = input.as_ref();
+        let mut group = c.benchmark_group(format!("{}: {}", name, day_name(i.div_ceil(2))));
+        group.bench_function("Default", |b| b.iter(|| function(black_box(input))));
+        for (variant, variant_name) in alternatives {
+            group.bench_function(*variant_name, |b| b.iter(|| variant(black_box(input))));
+        }
+        group.finish();
+    }
 }
 
 criterion_group!(benches, criterion_benchmark);
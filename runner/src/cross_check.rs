@@ -0,0 +1,238 @@
+use std::{
+    fs, io, panic,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use colored::Colorize;
+use fxhash::FxHashMap as HashMap;
+use shared::{
+    colored_time,
+    parse::{
+        normalize::{normalize, Normalized},
+        Parsable,
+    },
+    Solution, PART_BUDGET,
+};
+
+use crate::{day, PARTS};
+
+pub struct Account {
+    pub name: String,
+    pub inputs: Vec<Option<String>>,
+    pub changes: Vec<Normalized>,
+    pub answers: HashMap<String, String>,
+}
+
+pub enum Outcome {
+    Pass(Duration),
+    Fail(Duration, String),
+    Unchecked(Duration),
+    Unsolved,
+    Panicked,
+    Missing,
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Fail(_, _) | Outcome::Panicked)
+    }
+}
+
+// Expects `<dir>/<account>/<day>.txt` inputs with days written as `1.txt` or `01.txt`, and an
+// optional `<dir>/<account>/answers.txt` with one `<id> <answer>` per line, e.g. `01.1 2756096`.
+pub fn load_accounts(dir: &Path) -> io::Result<Vec<Account>> {
+    let mut accounts = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut inputs = Vec::with_capacity(25);
+        let mut changes = Vec::with_capacity(25);
+        for day in 1..=25 {
+            let input = fs::read_to_string(path.join(format!("{:02}.txt", day)))
+                .or_else(|_| fs::read_to_string(path.join(format!("{}.txt", day))))
+                .ok();
+
+            let (input, change) = match input {
+                Some(input) => {
+                    let (normalized, change) = normalize(&input);
+                    (Some(normalized.into_owned()), change)
+                }
+                None => (None, Normalized::default()),
+            };
+            inputs.push(input);
+            changes.push(change);
+        }
+
+        accounts.push(Account {
+            name,
+            inputs,
+            changes,
+            answers: load_answers(&path.join("answers.txt")),
+        });
+    }
+
+    accounts.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Ok(accounts)
+}
+
+pub fn load_answers(path: &Path) -> HashMap<String, String> {
+    let mut answers = HashMap::default();
+    if let Ok(file) = fs::read_to_string(path) {
+        for line in file.lines() {
+            if let Some((id, answer)) = line.trim().split_once(char::is_whitespace) {
+                answers.insert(id.to_owned(), answer.trim().to_owned());
+            }
+        }
+    }
+
+    answers
+}
+
+pub fn check(account: &Account, index: u32) -> Outcome {
+    let (function, _, id) = day(index);
+    let day: usize = id.bytes().next_number().unwrap();
+
+    let Some(input) = &account.inputs[day - 1] else {
+        return Outcome::Missing;
+    };
+
+    let result = panic::catch_unwind(|| {
+        let start = Instant::now();
+        let result = function(input);
+        (result, start.elapsed())
+    });
+
+    match result {
+        Ok((Solution::None, _)) => Outcome::Unsolved,
+        Ok((result, time)) => match account.answers.get(*id) {
+            Some(expected) if *expected == result.to_string() => Outcome::Pass(time),
+            Some(_) => Outcome::Fail(time, result.to_string()),
+            None => Outcome::Unchecked(time),
+        },
+        Err(_) => Outcome::Panicked,
+    }
+}
+
+pub fn run(dir: &Path) -> bool {
+    let accounts = match load_accounts(dir) {
+        Ok(accounts) if !accounts.is_empty() => accounts,
+        Ok(_) => {
+            println!("No accounts found in {}", dir.display());
+            return false;
+        }
+        Err(error) => {
+            println!("Unable to read {}: {}", dir.display(), error);
+            return false;
+        }
+    };
+
+    for account in accounts.iter() {
+        for (day, change) in account.changes.iter().enumerate() {
+            if !change.is_clean() {
+                println!("{} day {}: {}", account.name.yellow(), day + 1, change);
+            }
+        }
+    }
+
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let outcomes: Vec<Vec<Outcome>> = (1..=PARTS)
+        .map(|index| {
+            accounts
+                .iter()
+                .map(|account| check(account, index))
+                .collect()
+        })
+        .collect();
+
+    panic::set_hook(previous_hook);
+
+    print_matrix(&accounts, &outcomes);
+
+    !outcomes.iter().flatten().any(Outcome::is_failure)
+}
+
+pub fn print_matrix(accounts: &[Account], outcomes: &[Vec<Outcome>]) {
+    let widths: Vec<usize> = accounts
+        .iter()
+        .map(|account| account.name.len().max(14))
+        .collect();
+
+    let mut header = format!("{: <6}", "Part");
+    for (account, width) in accounts.iter().zip(widths.iter()) {
+        header.push_str(&format!(" {: >width$}", account.name, width = width));
+    }
+    println!("{}", header.cyan().bold());
+
+    let (mut passed, mut failed) = (0, 0);
+    for (index, row) in outcomes.iter().enumerate() {
+        let (_, _, id) = day(index as u32 + 1);
+        let mut line = format!("{: <6}", id.cyan().bold());
+
+        for (outcome, width) in row.iter().zip(widths.iter()) {
+            let width = width - 2;
+            let cell = match outcome {
+                Outcome::Pass(time) => {
+                    passed += 1;
+                    format!(
+                        "{} {: >width$}",
+                        "✓".green(),
+                        colored_time(*time, PART_BUDGET)
+                    )
+                }
+                Outcome::Fail(time, _) => {
+                    failed += 1;
+                    format!(
+                        "{} {: >width$}",
+                        "✗".red(),
+                        colored_time(*time, PART_BUDGET)
+                    )
+                }
+                Outcome::Unchecked(time) => {
+                    format!(
+                        "{} {: >width$}",
+                        "?".yellow(),
+                        colored_time(*time, PART_BUDGET)
+                    )
+                }
+                Outcome::Panicked => {
+                    failed += 1;
+                    format!("{} {: >width$}", "✗".red(), "panic".red())
+                }
+                Outcome::Unsolved => format!("  {: >width$}", "unsolved".dimmed()),
+                Outcome::Missing => format!("  {: >width$}", "-".dimmed()),
+            };
+            line.push(' ');
+            line.push_str(&cell);
+        }
+        println!("{}", line);
+
+        for (account, outcome) in accounts.iter().zip(row.iter()) {
+            if let Outcome::Fail(_, result) = outcome {
+                println!(
+                    "{: >6} {} => {}, expected {}",
+                    "",
+                    account.name.red(),
+                    result.bold(),
+                    account.answers[*id].bold()
+                );
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed",
+        passed.to_string().green().bold(),
+        failed.to_string().red().bold()
+    );
+}
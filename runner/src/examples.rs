@@ -0,0 +1,88 @@
+use std::panic;
+
+use colored::Colorize;
+use shared::{
+    colored_time,
+    examples::{check, discover, Verdict},
+    parse::Parsable,
+    PART_BUDGET,
+};
+
+use crate::{day, variants, Part, PARTS};
+
+pub fn run() -> bool {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let (mut passed, mut failed) = (0, 0);
+    for index in 1..=PARTS {
+        let (function, _, id) = day(index);
+        let mut bytes = id.bytes();
+        let day: usize = bytes.next_number().unwrap();
+        let part: u8 = bytes.next_number().unwrap();
+
+        let dir = format!("{}/../day-{:02}/src", env!("CARGO_MANIFEST_DIR"), day);
+        let mut functions: Vec<(Part, String)> = vec![(*function, id.to_string())];
+        for (variant, name) in variants(index) {
+            functions.push((*variant, format!("{} ({})", id, name)));
+        }
+
+        for example in discover(&dir, part) {
+            if !example.changes.is_clean() {
+                println!(
+                    "{: >12} {} {}: {}",
+                    "",
+                    format!("{}: {}", id, example.name).cyan().bold(),
+                    "input".yellow(),
+                    example.changes
+                );
+            }
+
+            for (function, name) in functions.iter() {
+                let (verdict, time) = check(function, &example);
+                let title = format!("{}: {}", name, example.name).cyan().bold();
+
+                match verdict {
+                    Verdict::Pass => {
+                        passed += 1;
+                        println!(
+                            "{: >12} {} {} => {}",
+                            colored_time(time, PART_BUDGET),
+                            "✓".green(),
+                            title,
+                            example.expected.bold()
+                        );
+                    }
+                    Verdict::Fail(result) => {
+                        failed += 1;
+                        println!(
+                            "{: >12} {} {} => {}, expected {}",
+                            colored_time(time, PART_BUDGET),
+                            "✗".red(),
+                            title,
+                            result.bold(),
+                            example.expected.bold()
+                        );
+                    }
+                    Verdict::Unsolved => {
+                        println!("{: >12} {} {} => unsolved", "", "?".yellow(), title);
+                    }
+                    Verdict::Panicked => {
+                        failed += 1;
+                        println!("{: >12} {} {} => {}", "", "✗".red(), title, "panic".red());
+                    }
+                }
+            }
+        }
+    }
+
+    panic::set_hook(previous_hook);
+
+    println!(
+        "{} passed, {} failed",
+        passed.to_string().green().bold(),
+        failed.to_string().red().bold()
+    );
+
+    failed == 0
+}
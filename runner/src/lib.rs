@@ -1,5 +1,7 @@
 use shared::Solution;
 
+pub const PARTS: u32 = 49;
+
 pub fn day(index: u32) -> &'static (fn(&str) -> Solution, &'static str, &'static str) {
     match index {
         1 => &(day_01::part_1, day_01::_INPUT, "01.1"),
@@ -54,3 +56,19 @@ pub fn day(index: u32) -> &'static (fn(&str) -> Solution, &'static str, &'static
         _ => panic!("Index out of bounds"),
     }
 }
+
+pub type Part = fn(&str) -> Solution;
+
+pub type Variant = (Part, &'static str);
+
+pub fn variants(index: u32) -> &'static [Variant] {
+    match index {
+        32 => &[(day_16::part_2_binary_heap, "Binary heap")],
+        _ => &[],
+    }
+}
+
+pub mod cross_check;
+pub mod examples;
+pub mod serve;
+pub mod tui;
@@ -1,25 +1,110 @@
-use std::time::Duration;
+use std::{env, path::Path, process, time::Duration};
 
-use runner::day;
-use shared::{parse::Parsable, *};
+use colored::Colorize;
+use runner::{cross_check, day, examples, serve, tui, variants, PARTS};
+use shared::{
+    parse::{normalize::normalize, Parsable},
+    *,
+};
 use std::panic;
 
 extern crate shared;
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("--inputs") => {
+            let dir = args.get(1).map(String::as_str).unwrap_or("inputs");
+            if !cross_check::run(Path::new(dir)) {
+                process::exit(1);
+            }
+        }
+        Some("--examples") => {
+            if !examples::run() {
+                process::exit(1);
+            }
+        }
+        Some("--tui") => {
+            let answers = cross_check::load_answers(Path::new(
+                args.get(1).map(String::as_str).unwrap_or("answers.txt"),
+            ));
+            if let Err(error) = tui::run(answers) {
+                println!("Terminal error: {}", error);
+                process::exit(1);
+            }
+        }
+        Some("serve") => {
+            let address = args.get(1).map(String::as_str).unwrap_or("127.0.0.1:2024");
+            if let Err(error) = serve::serve(address) {
+                println!("Unable to serve on {}: {}", address, error);
+                process::exit(1);
+            }
+        }
+        _ => run_all(),
+    }
+}
+
+fn run_all() {
     let mut time: Duration = Duration::new(0, 0);
-    for i in 1..=49 {
+    let mut disagreed = false;
+    for i in 1..=PARTS {
         let (function, input, id) = day(i);
         let day = id.bytes().next_number().unwrap();
 
-        let result = panic::catch_unwind(|| execute(function, input, id, day_name(day)));
+        let (input, changes) = normalize(input);
+        if !changes.is_clean() && i % 2 == 1 {
+            println!("{: >12} {}: {}", "", "Input".yellow(), changes);
+        }
+        let input: &str = &input;
+
+        let result = panic::catch_unwind(|| {
+            if variants(i).is_empty() {
+                (execute(function, input, id, day_name(day)), true)
+            } else {
+                execute_variants(i, input, id, day_name(day))
+            }
+        });
 
         match result {
-            Ok(duration) => time += duration,
+            Ok((duration, agreed)) => {
+                time += duration;
+                disagreed |= !agreed;
+            }
             Err(_) => {
                 println!("Day {} failed", day);
             }
         }
     }
     total(time);
+
+    if disagreed {
+        process::exit(1);
+    }
+}
+
+// Also tells whether every variant came up with the same answer as the default.
+fn execute_variants(index: u32, input: &str, id: &str, name: &str) -> (Duration, bool) {
+    let (function, _, _) = day(index);
+    let (expected, time) = measure(function, input);
+
+    if let Solution::None = expected {
+        return (Duration::ZERO, true);
+    }
+
+    report(&expected, time, id, name);
+
+    let mut agreed = true;
+    for (variant, variant_name) in variants(index) {
+        let (result, variant_time) = measure(variant, input);
+        let name = format!("{} ({})", name, variant_name);
+        report(&result, variant_time, id, &name);
+
+        if result != expected {
+            println!("{: >12} {} disagrees with {}", "", variant_name, expected);
+            agreed = false;
+        }
+    }
+
+    (time, agreed)
 }
@@ -0,0 +1,294 @@
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    panic,
+    time::Duration,
+};
+
+use shared::{day_name, measure, parse::normalize::normalize, Solution};
+
+use crate::{day, variants, PARTS};
+
+// Real inputs are tens of kilobytes, so anything past this is a mistake or abuse.
+const MAX_BODY: usize = 1 << 20;
+// A client that stops sending would otherwise hold up the one thread that answers requests.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+pub fn serve(address: &str) -> io::Result<()> {
+    let listener = TcpListener::bind(address)?;
+    println!("Listening on http://{}", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        if let Err(error) = handle(stream?) {
+            println!("Request failed: {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle(mut stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let (status, body) = match read_request(&mut stream)? {
+        Some(request) => route(&request.method, &request.path, &request.body),
+        None => error(413, "Input is too large"),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason(status),
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+// `None` when the body is over `MAX_BODY`, which is then left unread.
+fn read_request(stream: &mut TcpStream) -> io::Result<Option<Request>> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_owned();
+    let path = parts.next().unwrap_or_default().to_owned();
+
+    let mut length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    if length > MAX_BODY {
+        return Ok(None);
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Some(Request {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    }))
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+pub fn route(method: &str, path: &str, body: &str) -> (u16, String) {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["days"]) => (200, days()),
+        ("POST", ["day", day, "part", part]) => match (day.parse(), part.parse()) {
+            (Ok(day), Ok(part)) => solve(day, part, body),
+            _ => error(400, "Day and part must be numbers"),
+        },
+        (_, ["days"]) | (_, ["day", _, "part", _]) => error(405, "Method not allowed"),
+        _ => error(404, "Not found"),
+    }
+}
+
+fn solve(number: u32, part: u32, input: &str) -> (u16, String) {
+    if !(1..=25).contains(&number) || !(1..=2).contains(&part) {
+        return error(404, "No such day or part");
+    }
+
+    let index = (number - 1) * 2 + part;
+    if index > PARTS {
+        return error(404, "No such day or part");
+    }
+
+    let (function, _, id) = day(index);
+    let (input, changes) = normalize(input);
+    let normalized = if changes.is_clean() {
+        "null".to_owned()
+    } else {
+        quote(&changes.to_string())
+    };
+
+    match panic::catch_unwind(|| measure(function, &input)) {
+        Ok((result, time)) => (
+            200,
+            format!(
+                "{{\"id\":{},\"solution\":{},\"time_ns\":{},\"normalized\":{}}}",
+                quote(id),
+                solution(&result),
+                time.as_nanos(),
+                normalized
+            ),
+        ),
+        Err(_) => error(500, "Solver panicked"),
+    }
+}
+
+fn days() -> String {
+    let mut days = Vec::new();
+    for number in 1..=25 {
+        let mut parts = Vec::new();
+        for index in (number * 2 - 1)..=(number * 2).min(PARTS) {
+            let (_, _, id) = day(index);
+            let mut names = vec![quote("Default")];
+            names.extend(variants(index).iter().map(|(_, name)| quote(name)));
+
+            parts.push(format!(
+                "{{\"part\":{},\"id\":{},\"variants\":[{}]}}",
+                (index + 1) % 2 + 1,
+                quote(id),
+                names.join(",")
+            ));
+        }
+
+        days.push(format!(
+            "{{\"day\":{},\"name\":{},\"parts\":[{}]}}",
+            number,
+            quote(day_name(number)),
+            parts.join(",")
+        ));
+    }
+
+    format!("[{}]", days.join(","))
+}
+
+fn solution(solution: &Solution) -> String {
+    match solution {
+        Solution::Str(x) => quote(x),
+        Solution::Point(point) => format!("{{\"x\":{},\"y\":{}}}", point.x, point.y),
+        Solution::None => "null".to_owned(),
+        number => number.to_string(),
+    }
+}
+
+fn error(status: u16, message: &str) -> (u16, String) {
+    (status, format!("{{\"error\":{}}}", quote(message)))
+}
+
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            char if (char as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", char as u32)),
+            char => quoted.push(char),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod serve_tests {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    use super::*;
+    use test_case::test_case;
+
+    fn request(request: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || handle(listener.accept().unwrap().0).unwrap());
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        server.join().unwrap();
+
+        response
+    }
+
+    #[test]
+    fn solve_over_http() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let response = request(&format!(
+            "POST /day/1/part/2 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            input.len(),
+            input
+        ));
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("\"id\":\"01.2\",\"solution\":31,"));
+        assert!(response.ends_with("\"normalized\":null}"));
+    }
+
+    #[test]
+    fn normalizes_crlf_over_http() {
+        let input = "3   4\r\n4   3\r\n2   5\r\n1   3\r\n3   9\r\n3   3\r\n";
+        let (status, body) = route("POST", "/day/1/part/1", input);
+
+        assert_eq!(status, 200);
+        assert!(body.contains("\"solution\":11,"));
+        assert!(body.contains("\"normalized\":\"converted 6 CRLF line endings\""));
+    }
+
+    #[test]
+    fn days_over_http() {
+        let response = request("GET /days HTTP/1.1\r\nHost: localhost\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("{\"day\":16,\"name\":\"Reindeer Maze\""));
+        assert!(response.contains("\"variants\":[\"Default\",\"Binary heap\"]"));
+    }
+
+    #[test]
+    fn rejects_oversized_body() {
+        let response = request(&format!(
+            "POST /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY + 1
+        ));
+
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"));
+    }
+
+    #[test_case("GET", "/day/1/part/1", 405)]
+    #[test_case("POST", "/day/26/part/1", 404)]
+    #[test_case("POST", "/day/25/part/2", 404)]
+    #[test_case("POST", "/day/one/part/1", 400)]
+    #[test_case("GET", "/nothing", 404)]
+    fn rejected_routes(method: &str, path: &str, expected: u16) {
+        assert_eq!(route(method, path, "").0, expected);
+    }
+
+    #[test_case(Solution::Usize(527), "527")]
+    #[test_case(Solution::I64(-3), "-3")]
+    #[test_case(Solution::Str("4,1,5".to_owned()), "\"4,1,5\"")]
+    #[test_case(Solution::Str("a\"b".to_owned()), "\"a\\\"b\"")]
+    #[test_case(Solution::None, "null")]
+    fn solution_json(value: Solution, expected: &str) {
+        assert_eq!(solution(&value), expected);
+    }
+}
@@ -0,0 +1,246 @@
+use std::{io, ops::RangeInclusive, panic, time::Duration};
+
+use fxhash::FxHashMap as HashMap;
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    DefaultTerminal, Frame,
+};
+use shared::{day_name, measure, parse::normalize::normalize, time_color, Solution, PART_BUDGET};
+
+use crate::{day, variants, Part, PARTS};
+
+pub struct Run {
+    pub name: &'static str,
+    pub result: Option<Solution>,
+    pub time: Duration,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Wrong,
+    Unsolved,
+}
+
+struct App {
+    runs: Vec<Vec<Run>>,
+    answers: HashMap<String, String>,
+    selected: usize,
+}
+
+pub fn run(answers: HashMap<String, String>) -> io::Result<()> {
+    let mut app = App {
+        runs: (1..=PARTS).map(run_part).collect(),
+        answers,
+        selected: 1,
+    };
+
+    let mut terminal = ratatui::init();
+    let result = app.event_loop(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+fn parts(day: usize) -> RangeInclusive<u32> {
+    (day as u32 * 2 - 1)..=(day as u32 * 2).min(PARTS)
+}
+
+fn run_part(index: u32) -> Vec<Run> {
+    let (function, input, _) = day(index);
+    let (input, _) = normalize(input);
+    let input: &str = &input;
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut runs = vec![run_quietly(*function, input, "Default")];
+    for (variant, name) in variants(index) {
+        runs.push(run_quietly(*variant, input, name));
+    }
+
+    panic::set_hook(previous_hook);
+    runs
+}
+
+fn run_quietly(f: Part, input: &str, name: &'static str) -> Run {
+    match panic::catch_unwind(|| measure(&f, input)) {
+        Ok((result, time)) => Run {
+            name,
+            result: Some(result),
+            time,
+        },
+        Err(_) => Run {
+            name,
+            result: None,
+            time: Duration::ZERO,
+        },
+    }
+}
+
+fn timing(time: Duration) -> Span<'static> {
+    let (red, green, blue) = time_color(time, PART_BUDGET);
+    Span::styled(
+        format!("{:#?}", time),
+        Style::new().fg(Color::Rgb(red, green, blue)),
+    )
+}
+
+impl App {
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Left | KeyCode::Char('h') if self.selected > 1 => self.selected -= 1,
+                    KeyCode::Right | KeyCode::Char('l') if self.selected < 25 => self.selected += 1,
+                    KeyCode::Up | KeyCode::Char('k') if self.selected > 5 => self.selected -= 5,
+                    KeyCode::Down | KeyCode::Char('j') if self.selected <= 20 => self.selected += 5,
+                    KeyCode::Enter | KeyCode::Char('r') => {
+                        for index in parts(self.selected) {
+                            self.runs[index as usize - 1] = run_part(index);
+                        }
+                    }
+                    KeyCode::Char('a') => self.runs = (1..=PARTS).map(run_part).collect(),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn status(&self, index: u32) -> Status {
+        let (_, _, id) = day(index);
+        let runs = &self.runs[index as usize - 1];
+
+        match &runs[0].result {
+            None => Status::Wrong,
+            Some(Solution::None) => Status::Unsolved,
+            Some(result) => {
+                let disagrees = runs[1..]
+                    .iter()
+                    .any(|run| run.result.as_ref() != Some(result));
+                let incorrect = self
+                    .answers
+                    .get(*id)
+                    .is_some_and(|expected| *expected != result.to_string());
+
+                if disagrees || incorrect {
+                    Status::Wrong
+                } else {
+                    Status::Solved
+                }
+            }
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [calendar, output, help] = Layout::vertical([
+            Constraint::Length(20),
+            Constraint::Min(6),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let rows = Layout::vertical([Constraint::Ratio(1, 5); 5]).split(calendar);
+        for (y, row) in rows.iter().enumerate() {
+            let cells = Layout::horizontal([Constraint::Ratio(1, 5); 5]).split(*row);
+            for (x, cell) in cells.iter().enumerate() {
+                self.draw_day(frame, *cell, y * 5 + x + 1);
+            }
+        }
+
+        self.draw_output(frame, output);
+
+        frame.render_widget(
+            Line::from(" ←↑↓→ select  enter re-run day  a re-run all  q quit").dim(),
+            help,
+        );
+    }
+
+    fn draw_day(&self, frame: &mut Frame, area: Rect, day: usize) {
+        let mut block = Block::bordered().title(format!(" Day {} ", day));
+        if day == self.selected {
+            block = block.border_style(Style::new().yellow().bold());
+        }
+
+        let lines: Vec<Line> = parts(day)
+            .map(|index| {
+                let run = &self.runs[index as usize - 1][0];
+                let status = self.status(index);
+                let symbol = match status {
+                    Status::Solved => "✓".green(),
+                    Status::Wrong => "✗".red(),
+                    Status::Unsolved => "·".dark_gray(),
+                };
+
+                let mut spans = vec![Span::raw(format!("{} ", (index + 1) % 2 + 1)), symbol];
+                if status != Status::Unsolved {
+                    spans.push(Span::raw(" "));
+                    spans.push(timing(run.time));
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn draw_output(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(
+            format!(
+                " Day {}: {} ",
+                self.selected,
+                day_name(self.selected as u32)
+            )
+            .bold(),
+        );
+
+        let mut lines = Vec::new();
+        let (_, input, _) = day(*parts(self.selected).start());
+        let (_, changes) = normalize(input);
+        if !changes.is_clean() {
+            lines.push(Line::from(vec![
+                "input ".yellow().bold(),
+                changes.to_string().into(),
+            ]));
+        }
+
+        for index in parts(self.selected) {
+            let (_, _, id) = day(index);
+            for run in self.runs[index as usize - 1].iter() {
+                let mut spans = vec![
+                    format!("{} ", id).cyan().bold(),
+                    format!("{: <12} ", run.name).into(),
+                ];
+
+                match &run.result {
+                    None => spans.push("panicked".red()),
+                    Some(Solution::None) => spans.push("unsolved".dark_gray()),
+                    Some(result) => {
+                        spans.push(timing(run.time));
+                        spans.push(" => ".into());
+                        spans.push(result.to_string().bold());
+
+                        if let Some(expected) = self.answers.get(*id) {
+                            if *expected != result.to_string() {
+                                spans.push(format!(", expected {}", expected).red());
+                            }
+                        }
+                    }
+                }
+
+                lines.push(Line::from(spans));
+            }
+        }
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
@@ -0,0 +1,118 @@
+use std::{
+    borrow::Cow,
+    fs, panic,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    parse::normalize::{normalize, Normalized},
+    Solution,
+};
+
+// Examples live next to the day as `_test.txt` or `_test_N.txt`, with the expected answer for
+// each part in a sidecar named after the example, e.g. `_test_1.part_2` for part 2 of `_test_1.txt`.
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: String,
+    pub changes: Normalized,
+}
+
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unsolved,
+    Panicked,
+}
+
+pub fn discover(dir: impl AsRef<Path>, part: u8) -> Vec<Example> {
+    let dir = dir.as_ref();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut examples: Vec<Example> = entries
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            let name = file_name.strip_suffix(".txt")?;
+            if name != "_test" && !name.starts_with("_test_") {
+                return None;
+            }
+
+            let expected = fs::read_to_string(dir.join(format!("{}.part_{}", name, part))).ok()?;
+            let input = fs::read_to_string(dir.join(&file_name)).ok()?;
+            let (input, changes) = normalize(&input);
+
+            Some(Example {
+                name: name.to_owned(),
+                input: input.into_owned(),
+                expected: expected.trim().to_owned(),
+                changes,
+            })
+        })
+        .collect();
+
+    examples.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    examples
+}
+
+pub fn check(f: &dyn Fn(&str) -> Solution, example: &Example) -> (Verdict, Duration) {
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let start = Instant::now();
+        let result = f(&example.input);
+        (result, start.elapsed())
+    }));
+
+    match result {
+        Ok((Solution::None, time)) => (Verdict::Unsolved, time),
+        Ok((result, time)) if result.to_string() == example.expected => (Verdict::Pass, time),
+        Ok((result, time)) => (Verdict::Fail(result.to_string()), time),
+        Err(_) => (Verdict::Panicked, Duration::ZERO),
+    }
+}
+
+// For tests that call a part directly, so their input arrives the way the runner and
+// `example_tests!` would hand it over.
+pub fn normalized(input: &str) -> Cow<'_, str> {
+    normalize(input).0
+}
+
+pub fn assert_examples(dir: &str, part: u8, f: fn(&str) -> Solution) {
+    let examples = discover(dir, part);
+    assert!(!examples.is_empty(), "No examples found for part {}", part);
+
+    let failures: Vec<String> = examples
+        .iter()
+        .filter_map(|example| match check(&f, example).0 {
+            Verdict::Pass => None,
+            Verdict::Fail(result) => Some(format!(
+                "{}: got {}, expected {}",
+                example.name, result, example.expected
+            )),
+            Verdict::Unsolved => Some(format!("{}: unsolved", example.name)),
+            Verdict::Panicked => Some(format!("{}: panicked", example.name)),
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[macro_export]
+macro_rules! example_tests {
+    ($($function:ident => $part:literal),* $(,)?) => {
+        #[cfg(test)]
+        mod example_tests {
+            $(
+                #[test]
+                fn $function() {
+                    $crate::examples::assert_examples(
+                        concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+                        $part,
+                        super::$function,
+                    );
+                }
+            )*
+        }
+    };
+}
@@ -0,0 +1,491 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index};
+
+use crate::points::{directions::Direction, ipoint::IPoint, point::Point};
+
+use super::{iterators::*, Grid};
+
+const WORD: usize = u64::BITS as usize;
+
+// A grid of booleans packed 64 to a word, each row starting on a fresh word so whole rows can be
+// combined at once. Bit `x % 64` of word `x / 64` holds column `x`, and the bits past the width
+// in the last word of a row are always zero, so counting and comparing never need a mask.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    stride: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl BitGrid {
+    pub fn filled(fill: bool, height: usize, width: usize) -> Self {
+        // An empty row still gets a word, so rows can always be chunked.
+        let stride = width.div_ceil(WORD).max(1);
+        let mut grid = Self {
+            words: vec![0; stride * height],
+            stride,
+            width,
+            height,
+        };
+        if fill {
+            grid.fill(true);
+        }
+        grid
+    }
+
+    pub fn same_size(&self) -> Self {
+        Self::filled(false, self.height, self.width)
+    }
+
+    // The bits of the last word of a row that are inside the grid.
+    fn tail_mask(&self) -> u64 {
+        match (self.width, self.width % WORD) {
+            (0, _) => 0,
+            (_, 0) => u64::MAX,
+            (_, bits) => (1 << bits) - 1,
+        }
+    }
+
+    fn locate(&self, point: Point) -> (usize, u64) {
+        // Past the width the bit would land in the padding, or in the next row, without panicking.
+        assert!(self.is_within_bounds(point), "{} is off the grid", point);
+        (
+            point.y * self.stride + point.x / WORD,
+            1 << (point.x % WORD),
+        )
+    }
+
+    pub fn is_within_bounds(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> bool {
+        let (word, bit) = self.locate(point);
+        self.words[word] & bit != 0
+    }
+
+    pub fn set(&mut self, point: Point, value: bool) {
+        let (word, bit) = self.locate(point);
+        if value {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+    }
+
+    // Sets the cell, telling whether it was clear before, the way `HashSet::insert` does.
+    pub fn insert(&mut self, point: Point) -> bool {
+        let (word, bit) = self.locate(point);
+        let fresh = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        fresh
+    }
+
+    pub fn toggle(&mut self, point: Point) {
+        let (word, bit) = self.locate(point);
+        self.words[word] ^= bit;
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn fill(&mut self, value: bool) {
+        if !value {
+            self.clear();
+            return;
+        }
+
+        self.words.fill(u64::MAX);
+        let mask = self.tail_mask();
+        for row in self.words.chunks_exact_mut(self.stride) {
+            row[row.len() - 1] &= mask;
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    pub fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.stride..(y + 1) * self.stride]
+    }
+
+    pub fn row_count(&self, y: usize) -> usize {
+        self.row(y)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    // Rows from another grid of the same width, or built by hand with the same layout.
+    pub fn and_row(&mut self, y: usize, row: &[u64]) {
+        self.combine_row(y, row, |word, other| word & other);
+    }
+
+    pub fn or_row(&mut self, y: usize, row: &[u64]) {
+        self.combine_row(y, row, |word, other| word | other);
+    }
+
+    pub fn xor_row(&mut self, y: usize, row: &[u64]) {
+        self.combine_row(y, row, |word, other| word ^ other);
+    }
+
+    fn combine_row(&mut self, y: usize, row: &[u64], f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            row.len(),
+            self.stride,
+            "Rows are {} words long",
+            self.stride
+        );
+        let mask = self.tail_mask();
+        let start = y * self.stride;
+        let words = &mut self.words[start..start + self.stride];
+        for (word, other) in words.iter_mut().zip(row) {
+            *word = f(*word, *other);
+        }
+        words[self.stride - 1] &= mask;
+    }
+
+    fn combine(&mut self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "Grids differ in size"
+        );
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word = f(*word, *other);
+        }
+    }
+
+    // Moves every cell one step in `direction`, cells that go over the edge are lost and the
+    // ones coming in from the other side are clear.
+    pub fn shift(&mut self, direction: Direction) {
+        let offset = IPoint::new(0, 0).go(direction);
+        match offset.y {
+            -1 => self.shift_rows_up(),
+            1 => self.shift_rows_down(),
+            _ => {}
+        }
+        match offset.x {
+            -1 => self.shift_columns_left(),
+            1 => self.shift_columns_right(),
+            _ => {}
+        }
+    }
+
+    pub fn shifted(&self, direction: Direction) -> Self {
+        let mut grid = self.clone();
+        grid.shift(direction);
+        grid
+    }
+
+    fn shift_rows_up(&mut self) {
+        let stride = self.stride;
+        let length = self.words.len();
+        self.words.copy_within(stride.min(length).., 0);
+        self.words[length.saturating_sub(stride)..].fill(0);
+    }
+
+    fn shift_rows_down(&mut self) {
+        let stride = self.stride;
+        let length = self.words.len();
+        self.words
+            .copy_within(..length.saturating_sub(stride), stride.min(length));
+        self.words[..stride.min(length)].fill(0);
+    }
+
+    fn shift_columns_right(&mut self) {
+        let mask = self.tail_mask();
+        for row in self.words.chunks_exact_mut(self.stride) {
+            let mut carry = 0;
+            for word in row.iter_mut() {
+                let next = *word >> (WORD - 1);
+                *word = *word << 1 | carry;
+                carry = next;
+            }
+            row[row.len() - 1] &= mask;
+        }
+    }
+
+    fn shift_columns_left(&mut self) {
+        for row in self.words.chunks_exact_mut(self.stride) {
+            let mut carry = 0;
+            for word in row.iter_mut().rev() {
+                let next = *word << (WORD - 1);
+                *word = *word >> 1 | carry;
+                carry = next;
+            }
+        }
+    }
+
+    // Only the set cells, row by row.
+    pub fn ones(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let (y, x) = (index / self.stride, index % self.stride * WORD);
+                std::iter::successors((word != 0).then_some(word), |word| {
+                    Some(word & (word - 1)).filter(|word| *word != 0)
+                })
+                .map(move |word| Point::new(x + word.trailing_zeros() as usize, y))
+            })
+    }
+
+    pub fn points(&self) -> Positions {
+        Positions {
+            height: self.height,
+            width: self.width,
+            x: 0,
+            y: 0,
+        }
+    }
+
+    pub fn go(&self, point: Point, direction: Direction) -> Option<Point> {
+        Point::try_from(IPoint::from(point).go(direction))
+            .ok()
+            .filter(|point| self.is_within_bounds(*point))
+    }
+
+    pub fn go_if_true(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.go(point, direction).filter(|point| self.get(*point))
+    }
+
+    pub fn adjacent_eight(&self, point: Point) -> AdjecentEight {
+        AdjecentEight {
+            x: point.x,
+            y: point.y,
+            height: self.height,
+            width: self.width,
+            current: 0,
+        }
+    }
+
+    pub fn adjacent_four(&self, point: Point) -> AdjacentFour {
+        AdjacentFour {
+            x: point.x,
+            y: point.y,
+            height: self.height,
+            width: self.width,
+            current: 0,
+        }
+    }
+
+    pub fn adjacent_four_directional(&self, point: Point) -> AdjacentFourDirectional {
+        AdjacentFourDirectional {
+            x: point.x,
+            y: point.y,
+            height: self.height,
+            width: self.width,
+            current: 0,
+        }
+    }
+
+    pub fn adjacent_three_in_direction(
+        &self,
+        point: Point,
+        from: Direction,
+    ) -> AdjacentThreeInDirection {
+        AdjacentThreeInDirection {
+            x: point.x,
+            y: point.y,
+            height: self.height,
+            width: self.width,
+            current: 0,
+            direction: from.reverse(),
+        }
+    }
+
+    pub fn diagonals(&self, point: Point) -> Diagonals {
+        Diagonals {
+            x: point.x,
+            y: point.y,
+            height: self.height,
+            width: self.width,
+            current: 0,
+        }
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::new(
+            self.points().map(|point| self.get(point)).collect(),
+            self.width,
+            self.height,
+        )
+    }
+
+    pub fn print_bool(&self) -> String {
+        self.to_grid().print_bool()
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::filled(false, grid.height, grid.width);
+        for point in grid.points() {
+            if grid[point] {
+                bits.set(point, true);
+            }
+        }
+        bits
+    }
+}
+
+impl FromIterator<Point> for BitGrid {
+    // Just big enough for the largest coordinates given.
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        let points: Vec<Point> = iter.into_iter().collect();
+        let width = points.iter().map(|point| point.x + 1).max().unwrap_or(0);
+        let height = points.iter().map(|point| point.y + 1).max().unwrap_or(0);
+        let mut grid = Self::filled(false, height, width);
+        for point in points {
+            grid.set(point, true);
+        }
+        grid
+    }
+}
+
+// There is no bool to borrow inside a word, so indexing hands out references to constants and
+// writes go through `set`.
+impl Index<Point> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, index: Point) -> &bool {
+        if self.get(index) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl Index<(usize, usize)> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, index: (usize, usize)) -> &bool {
+        &self[Point::new(index.0, index.1)]
+    }
+}
+
+macro_rules! bitwise {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign:ident, $op:tt) => {
+        impl $assign_trait<&BitGrid> for BitGrid {
+            fn $assign(&mut self, other: &BitGrid) {
+                self.combine(other, |word, other| word $op other);
+            }
+        }
+
+        impl $trait<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+
+            fn $method(self, other: &BitGrid) -> BitGrid {
+                let mut grid = self.clone();
+                grid.$assign(other);
+                grid
+            }
+        }
+    };
+}
+
+bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+bitwise!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+#[cfg(test)]
+mod bits_tests {
+    use super::*;
+    use crate::points::directions::DIRECTIONS;
+
+    fn bits(input: &str) -> BitGrid {
+        BitGrid::from(&Grid::parse(input, |byte, _| Some(byte == b'#')).unwrap())
+    }
+
+    #[test]
+    fn get_and_set() {
+        let mut grid = BitGrid::filled(false, 3, 70);
+        assert!(grid.insert(Point::new(65, 1)));
+        assert!(!grid.insert(Point::new(65, 1)));
+        grid.set(Point::new(0, 2), true);
+        assert!(grid[Point::new(65, 1)] && grid[(0, 2)] && !grid[(1, 2)]);
+        assert_eq!(grid.count(), 2);
+        assert_eq!(grid.row(1), &[0, 2]);
+        assert_eq!(
+            grid.ones().collect::<Vec<_>>(),
+            vec![Point::new(65, 1), Point::new(0, 2)]
+        );
+
+        grid.toggle(Point::new(65, 1));
+        assert_eq!(grid.row_count(1), 0);
+        grid.fill(true);
+        assert_eq!(grid.count(), 210);
+        grid.clear();
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "off the grid")]
+    fn padding_is_off_the_grid() {
+        BitGrid::filled(false, 2, 3).set(Point::new(3, 0), true);
+    }
+
+    #[test]
+    fn neighbours_match_grid() {
+        let grid = bits("#..\n.#.\n..#\n");
+        let dense = grid.to_grid();
+        let point = Point::new(1, 1);
+        assert!(grid.adjacent_eight(point).eq(dense.adjacent_eight(point)));
+        assert_eq!(grid.go(Point::new(0, 0), Direction::North), None);
+        assert_eq!(
+            grid.go_if_true(point, Direction::SouthEast),
+            Some(Point::new(2, 2))
+        );
+        assert_eq!(grid.go_if_true(point, Direction::South), None);
+    }
+
+    #[test]
+    fn bitwise() {
+        let a = bits("##..\n..##\n");
+        let b = bits("#.#.\n.#.#\n");
+        assert_eq!(&a & &b, bits("#...\n...#\n"));
+        assert_eq!(&a | &b, bits("###.\n.###\n"));
+        assert_eq!(&a ^ &b, bits(".##.\n.##.\n"));
+
+        let mut c = a.clone();
+        c.or_row(0, b.row(1));
+        c.xor_row(1, &[u64::MAX]);
+        assert_eq!(c, bits("##.#\n##..\n"));
+    }
+
+    #[test]
+    fn shifts() {
+        let mut wide = BitGrid::filled(false, 2, 130);
+        wide.set(Point::new(63, 0), true);
+        wide.set(Point::new(129, 0), true);
+        wide.shift(Direction::SouthEast);
+        assert_eq!(wide.ones().collect::<Vec<_>>(), vec![Point::new(64, 1)]);
+        wide.shift(Direction::West);
+        wide.shift(Direction::North);
+        assert_eq!(wide.ones().collect::<Vec<_>>(), vec![Point::new(63, 0)]);
+
+        for direction in DIRECTIONS {
+            for width in [0, 3, 64, 130] {
+                let mut empty = BitGrid::filled(false, 0, width);
+                empty.shift(direction);
+                assert_eq!(empty, BitGrid::filled(false, 0, width));
+            }
+        }
+
+        let grid = bits("#.#\n.#.\n");
+        assert_eq!(grid.shifted(Direction::East), bits(".#.\n..#\n"));
+        assert_eq!(grid.shifted(Direction::South), bits("...\n#.#\n"));
+        assert_eq!(grid.shifted(Direction::NorthWest), bits("#..\n...\n"));
+    }
+}
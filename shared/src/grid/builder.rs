@@ -0,0 +1,199 @@
+use std::fmt::{Display, Formatter};
+
+use super::Grid;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GridError {
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    SizeMismatch {
+        length: usize,
+        width: usize,
+        height: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {} is {} wide, expected {}", row, found, expected),
+            GridError::SizeMismatch {
+                length,
+                width,
+                height,
+            } => write!(
+                f,
+                "{} cells don't fill a {}x{} grid of {}",
+                length,
+                width,
+                height,
+                width * height
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+// Checked counterparts of `new`, `from` and `overwrite`, which stay as the unchecked fast paths.
+impl<T> Grid<T> {
+    pub fn checked_new(vec: Vec<T>, width: usize, height: usize) -> Result<Self, GridError> {
+        if vec.len() != width * height {
+            return Err(GridError::SizeMismatch {
+                length: vec.len(),
+                width,
+                height,
+            });
+        }
+        Ok(Self::new(vec, width, height))
+    }
+
+    pub fn checked_from(vec: Vec<T>, height: usize) -> Result<Self, GridError> {
+        let width = vec.len().checked_div(height).unwrap_or(0);
+        Self::checked_new(vec, width, height)
+    }
+
+    pub fn from_rows<R>(rows: impl IntoIterator<Item = R>) -> Result<Self, GridError>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let mut builder = GridBuilder::new();
+        for row in rows {
+            builder.push_row(row)?;
+        }
+        Ok(builder.build())
+    }
+
+    pub fn try_overwrite(&mut self, vec: Vec<T>) -> Result<(), GridError> {
+        if vec.len() != self.vec.len() {
+            return Err(GridError::SizeMismatch {
+                length: vec.len(),
+                width: self.width,
+                height: self.height,
+            });
+        }
+        self.vec = vec;
+        Ok(())
+    }
+}
+
+// Collects rows one at a time, the first row decides the width.
+pub struct GridBuilder<T> {
+    vec: Vec<T>,
+    width: Option<usize>,
+    height: usize,
+}
+
+impl<T> Default for GridBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> GridBuilder<T> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(cells: usize) -> Self {
+        Self {
+            vec: Vec::with_capacity(cells),
+            width: None,
+            height: 0,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // A rejected row is not added, so the builder can still be used afterwards.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) -> Result<(), GridError> {
+        let start = self.vec.len();
+        self.vec.extend(row);
+        let found = self.vec.len() - start;
+
+        match self.width {
+            None => self.width = Some(found),
+            Some(expected) if expected != found => {
+                self.vec.truncate(start);
+                return Err(GridError::RaggedRow {
+                    row: self.height,
+                    expected,
+                    found,
+                });
+            }
+            _ => {}
+        }
+
+        self.height += 1;
+        Ok(())
+    }
+
+    pub fn build(self) -> Grid<T> {
+        Grid::new(self.vec, self.width.unwrap_or(0), self.height)
+    }
+}
+
+#[cfg(test)]
+mod grid_builder_tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn rows() {
+        let grid = Grid::from_rows(["#..".bytes(), ".#.".bytes()]).unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.row(1), b".#.");
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let result = Grid::from_rows(["###".bytes(), "###".bytes(), "##".bytes()]);
+        assert_eq!(
+            result.unwrap_err(),
+            GridError::RaggedRow {
+                row: 2,
+                expected: 3,
+                found: 2
+            }
+        );
+    }
+
+    #[test]
+    fn builder_recovers() {
+        let mut builder = GridBuilder::new();
+        builder.push_row([1, 2]).unwrap();
+        assert!(builder.push_row([3]).is_err());
+        builder.push_row([3, 4]).unwrap();
+        assert_eq!(builder.build().vec, vec![1, 2, 3, 4]);
+    }
+
+    #[test_case(vec![0; 6], 3, 2, true)]
+    #[test_case(vec![0; 7], 3, 2, false)]
+    #[test_case(vec![], 0, 0, true)]
+    fn checked_new(vec: Vec<u8>, width: usize, height: usize, valid: bool) {
+        assert_eq!(Grid::checked_new(vec, width, height).is_ok(), valid);
+    }
+
+    #[test]
+    fn checked_from() {
+        let error = Grid::checked_from(vec![0; 7], 2).unwrap_err();
+        assert_eq!(error.to_string(), "7 cells don't fill a 3x2 grid of 6");
+    }
+
+    #[test]
+    fn try_overwrite() {
+        let mut grid = Grid::new(vec![0; 4], 2, 2);
+        assert!(grid.try_overwrite(vec![1; 3]).is_err());
+        assert_eq!(grid.try_overwrite(vec![1; 4]), Ok(()));
+        assert_eq!(grid.vec, vec![1; 4]);
+    }
+}
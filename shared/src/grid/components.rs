@@ -0,0 +1,228 @@
+use crate::points::point::Point;
+
+use super::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+// What a region looks like from the outside. Perimeter and sides trace the outline of its cells,
+// holes included, and the bounding box runs from `min` to `max` inclusive. A hole is a patch of
+// other cells closed in by the region, connected the other way round from the region itself.
+// Sides and holes stay 0 until `Components::outlines` has counted them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub area: usize,
+    pub perimeter: usize,
+    pub sides: usize,
+    pub min: Point,
+    pub max: Point,
+    pub holes: usize,
+}
+
+// Every cell labelled with the index of its region in `regions`.
+#[derive(Clone, Debug)]
+pub struct Components {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+    pub connectivity: Connectivity,
+}
+
+// How the cells of one label fill a 2x2 window, one bit per cell in reading order.
+const DIAGONALS: [u8; 2] = [0b1001, 0b0110];
+
+impl Components {
+    pub fn region(&self, point: Point) -> &Region {
+        &self.regions[self.labels[point]]
+    }
+
+    // Every 2x2 window, the ones hanging over the edge included, settles the corners and the
+    // Euler number of each region in it. A region is one piece, so what's left of the Euler
+    // number is the holes. This is a second pass over the whole grid, so it's left to the callers
+    // that need it.
+    pub fn outlines(mut self) -> Self {
+        let labels = &self.labels;
+        let mut quads = vec![(0isize, 0isize, 0isize); self.regions.len()];
+        for y in 0..=labels.height {
+            for x in 0..=labels.width {
+                let window = [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(dx, dy)| {
+                    let (x, y) = ((x + dx).checked_sub(1)?, (y + dy).checked_sub(1)?);
+                    (x < labels.width && y < labels.height).then(|| labels[(x, y)])
+                });
+
+                for (i, label) in window.iter().enumerate() {
+                    let Some(label) = *label else {
+                        continue;
+                    };
+                    if window[..i].contains(&Some(label)) {
+                        continue;
+                    }
+
+                    let pattern = window
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, other)| **other == Some(label))
+                        .fold(0u8, |pattern, (bit, _)| pattern | 1 << bit);
+                    let (single, triple, diagonal) = &mut quads[label];
+                    let region = &mut self.regions[label];
+                    match pattern.count_ones() {
+                        1 => {
+                            *single += 1;
+                            region.sides += 1;
+                        }
+                        3 => {
+                            *triple += 1;
+                            region.sides += 1;
+                        }
+                        _ if DIAGONALS.contains(&pattern) => {
+                            *diagonal += 1;
+                            region.sides += 2;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for (region, (single, triple, diagonal)) in self.regions.iter_mut().zip(quads) {
+            // Cells touching only at a corner are apart for 4-connectivity and together for 8.
+            let euler = match self.connectivity {
+                Connectivity::Four => (single - triple + 2 * diagonal) / 4,
+                Connectivity::Eight => (single - triple - 2 * diagonal) / 4,
+            };
+            region.holes = (1 - euler) as usize;
+        }
+
+        self
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn components(&self, same: impl Fn(&T, &T) -> bool) -> Components {
+        self.components_with(Connectivity::Four, same)
+    }
+
+    // Neighbouring cells end up in one region when `same` holds for them.
+    pub fn components_with(
+        &self,
+        connectivity: Connectivity,
+        same: impl Fn(&T, &T) -> bool,
+    ) -> Components {
+        let mut labels = self.same_size_with(usize::MAX);
+        let mut regions = Vec::new();
+        let mut stack = Vec::new();
+
+        for start in self.points() {
+            if labels[start] != usize::MAX {
+                continue;
+            }
+
+            let label = regions.len();
+            let mut region = Region {
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                min: start,
+                max: start,
+                holes: 0,
+            };
+            labels[start] = label;
+            stack.push(start);
+
+            while let Some(point) = stack.pop() {
+                region.area += 1;
+                region.min = Point::new(region.min.x.min(point.x), region.min.y.min(point.y));
+                region.max = Point::new(region.max.x.max(point.x), region.max.y.max(point.y));
+
+                let diagonals =
+                    (connectivity == Connectivity::Eight).then(|| self.diagonals(point));
+                for next in self
+                    .adjacent_four(point)
+                    .chain(diagonals.into_iter().flatten())
+                {
+                    if labels[next] == usize::MAX && same(&self[point], &self[next]) {
+                        labels[next] = label;
+                        stack.push(next);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+
+        for point in labels.points() {
+            let label = labels[point];
+            let open = 4 - labels
+                .adjacent_four(point)
+                .filter(|next| labels[*next] == label)
+                .count();
+            regions[label].perimeter += open;
+        }
+
+        Components {
+            labels,
+            regions,
+            connectivity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod components_tests {
+    use super::*;
+
+    fn letters(input: &str) -> Grid<u8> {
+        Grid::parse(input, |byte, _| Some(byte)).unwrap()
+    }
+
+    #[test]
+    fn fences() {
+        let grid = letters("AAAA\nBBCD\nBBCC\nEEEC\n");
+        let components = grid.components(|a, b| a == b);
+        assert_eq!(components.regions.len(), 5);
+        assert!(components.regions.iter().all(|region| region.sides == 0));
+
+        let components = components.outlines();
+        let price: usize = components
+            .regions
+            .iter()
+            .map(|region| region.area * region.perimeter)
+            .sum();
+        let discounted: usize = components
+            .regions
+            .iter()
+            .map(|region| region.area * region.sides)
+            .sum();
+        assert_eq!((price, discounted), (140, 80));
+
+        let c = components.region(Point::new(2, 1));
+        assert_eq!((c.min, c.max), (Point::new(2, 1), Point::new(3, 3)));
+    }
+
+    #[test]
+    fn holes() {
+        let grid = letters("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n");
+        let components = grid.components(|a, b| a == b).outlines();
+        let outer = components.region(Point::new(0, 0));
+        assert_eq!(outer.holes, 4);
+        assert_eq!((outer.perimeter, outer.sides), (36, 20));
+        assert_eq!(components.region(Point::new(1, 1)).holes, 0);
+    }
+
+    #[test]
+    fn eight_connected() {
+        let grid = letters(".#.\n#.#\n.#.\n");
+        let four = grid.components(|a, b| a == b);
+        assert_eq!(four.regions.len(), 9);
+
+        let eight = grid
+            .components_with(Connectivity::Eight, |a, b| a == b)
+            .outlines();
+        assert_eq!(eight.regions.len(), 2);
+        let ring = eight.region(Point::new(1, 0));
+        assert_eq!((ring.area, ring.holes), (4, 1));
+        assert_eq!((ring.perimeter, ring.sides), (16, 16));
+        assert_eq!(eight.labels[(0, 0)], eight.labels[(2, 2)]);
+    }
+}
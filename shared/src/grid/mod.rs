@@ -511,4 +511,13 @@ where
     }
 }
 
+pub mod bits;
+pub mod builder;
+pub mod components;
 pub mod iterators;
+pub mod parse;
+pub mod ray;
+pub mod sparse;
+pub mod torus;
+pub mod transform;
+pub mod view;
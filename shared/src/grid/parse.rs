@@ -0,0 +1,125 @@
+use fxhash::FxHashMap as HashMap;
+
+use crate::{
+    parse::{ParseError, PositionedError},
+    points::point::Point,
+};
+
+use super::{
+    builder::{GridBuilder, GridError},
+    Grid,
+};
+
+pub type Markers = HashMap<u8, Vec<Point>>;
+
+impl<T> Grid<T> {
+    // Builds a grid from one row per line, `None` from `f` rejects the byte at that point.
+    pub fn parse(
+        input: &str,
+        f: impl FnMut(u8, Point) -> Option<T>,
+    ) -> Result<Self, PositionedError> {
+        Self::parse_with_markers(input, &[], f).map(|(grid, _)| grid)
+    }
+
+    // Like `parse`, and also records where each of the `markers` bytes was seen, e.g. b"SE".
+    pub fn parse_with_markers(
+        input: &str,
+        markers: &[u8],
+        mut f: impl FnMut(u8, Point) -> Option<T>,
+    ) -> Result<(Self, Markers), PositionedError> {
+        let bytes = input.as_bytes();
+        let mut builder = GridBuilder::with_capacity(bytes.len());
+        let mut found = Markers::default();
+
+        for line in input.trim_end_matches(['\n', '\r']).lines() {
+            let start = line.as_ptr() as usize - input.as_ptr() as usize;
+            let y = builder.height();
+
+            let mut unexpected = None;
+            let pushed = builder.push_row(line.bytes().enumerate().map_while(|(x, byte)| {
+                let point = Point::new(x, y);
+                if markers.contains(&byte) {
+                    found.entry(byte).or_default().push(point);
+                }
+
+                let value = f(byte, point);
+                if value.is_none() {
+                    unexpected = Some((x, byte));
+                }
+                value
+            }));
+
+            if let Some((x, byte)) = unexpected {
+                return Err(PositionedError::new(
+                    bytes,
+                    start + x,
+                    ParseError::Unexpected(byte),
+                ));
+            }
+            if let Err(error) = pushed {
+                // Points at the first missing or extra cell.
+                let offset = match error {
+                    GridError::RaggedRow {
+                        expected, found, ..
+                    } => start + expected.min(found),
+                    GridError::SizeMismatch { .. } => start,
+                };
+                return Err(PositionedError::new(bytes, offset, ParseError::Grid(error)));
+            }
+        }
+
+        Ok((builder.build(), found))
+    }
+}
+
+#[cfg(test)]
+mod grid_parse_tests {
+    use super::*;
+
+    fn tile(byte: u8, _: Point) -> Option<bool> {
+        match byte {
+            b'#' => Some(false),
+            b'.' | b'S' | b'E' => Some(true),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn markers() {
+        let (grid, markers) = Grid::parse_with_markers("#S.\n..E\n", b"SE", tile).unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.vec, vec![false, true, true, true, true, true]);
+        assert_eq!(markers[&b'S'], vec![Point::new(1, 0)]);
+        assert_eq!(markers[&b'E'], vec![Point::new(2, 1)]);
+    }
+
+    #[test]
+    fn crlf() {
+        let grid = Grid::parse("#.\r\n.#\r\n", tile).unwrap();
+        assert_eq!((grid.width, grid.height), (2, 2));
+    }
+
+    #[test]
+    fn unexpected() {
+        let error = Grid::parse("#.\n.x\n", tile).unwrap_err();
+        assert_eq!(error.error, ParseError::Unexpected(b'x'));
+        assert_eq!((error.offset, error.line, error.column), (4, 2, 2));
+    }
+
+    #[test]
+    fn ragged() {
+        let error = Grid::parse("###\n##\n###\n", tile).unwrap_err();
+        assert_eq!(
+            error.error,
+            ParseError::Grid(GridError::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "row 1 is 2 wide, expected 3 at line 2, column 3 (byte 6)"
+        );
+    }
+}
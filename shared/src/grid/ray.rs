@@ -0,0 +1,223 @@
+use crate::points::{
+    directions::{Direction, CARDINALS},
+    ipoint::IPoint,
+    point::Point,
+};
+
+use super::{bits::BitGrid, Grid};
+
+impl<T> Grid<T> {
+    // The points past `point`, one `step` apart, for as long as they stay on the grid. A step can
+    // be a direction or any offset, like the distance between two antennas.
+    pub fn ray(&self, point: Point, step: impl Into<IPoint>) -> Ray {
+        Ray::new(point, step.into(), self.width, self.height)
+    }
+
+    // The nearest cell past `point` that matches, looking along `step`.
+    pub fn first_hit(
+        &self,
+        point: Point,
+        step: impl Into<IPoint>,
+        matches: impl Fn(&T) -> bool,
+    ) -> Option<Point> {
+        self.ray(point, step).find(|point| matches(&self[*point]))
+    }
+
+    pub fn blockers(&self, blocked: impl Fn(&T) -> bool) -> Blockers {
+        let mut next = self.same_size_with([None; 4]);
+        // Each cell takes over from its neighbour on the side it looks towards, so that neighbour
+        // has to be done first: top down for north, left to right for west and so on.
+        for y in 0..self.height {
+            for x in 0..self.width {
+                for direction in [Direction::North, Direction::West] {
+                    next[(x, y)][direction as usize] =
+                        self.behind(&next, x, y, direction, &blocked);
+                }
+            }
+        }
+        for y in (0..self.height).rev() {
+            for x in (0..self.width).rev() {
+                for direction in [Direction::South, Direction::East] {
+                    next[(x, y)][direction as usize] =
+                        self.behind(&next, x, y, direction, &blocked);
+                }
+            }
+        }
+        Blockers { next }
+    }
+
+    fn behind(
+        &self,
+        next: &Grid<[Option<Point>; 4]>,
+        x: usize,
+        y: usize,
+        direction: Direction,
+        blocked: impl Fn(&T) -> bool,
+    ) -> Option<Point> {
+        let neighbour = self.go_xy(x, y, direction)?;
+        if blocked(&self[neighbour]) {
+            Some(neighbour)
+        } else {
+            next[neighbour][direction as usize]
+        }
+    }
+}
+
+impl BitGrid {
+    pub fn ray(&self, point: Point, step: impl Into<IPoint>) -> Ray {
+        Ray::new(point, step.into(), self.width, self.height)
+    }
+}
+
+pub struct Ray {
+    point: IPoint,
+    step: IPoint,
+    width: isize,
+    height: isize,
+}
+
+impl Ray {
+    fn new(point: Point, step: IPoint, width: usize, height: usize) -> Self {
+        assert!(step != IPoint::new(0, 0), "A ray needs a step to take");
+        Self {
+            point: IPoint::from(point),
+            step,
+            width: width as isize,
+            height: height as isize,
+        }
+    }
+}
+
+impl Iterator for Ray {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        let next = self.point + self.step;
+        if next.x < 0 || next.y < 0 || next.x >= self.width || next.y >= self.height {
+            return None;
+        }
+        self.point = next;
+        Some(Point::new(next.x as usize, next.y as usize))
+    }
+}
+
+// For every cell, the nearest blocked cell in each cardinal direction, so walking until something
+// is in the way takes a lookup instead of a step per cell.
+pub struct Blockers {
+    next: Grid<[Option<Point>; 4]>,
+}
+
+impl Blockers {
+    // `None` when nothing blocks the way to the edge.
+    pub fn next(&self, point: Point, direction: Direction) -> Option<Point> {
+        assert!(
+            CARDINALS.contains(&direction),
+            "{:?} is not cardinal",
+            direction
+        );
+        self.next[point][direction as usize]
+    }
+
+    // As `next`, with `extra` blocked too, for trying out one more obstacle without a new table.
+    pub fn next_with(&self, point: Point, direction: Direction, extra: Point) -> Option<Point> {
+        let next = self.next(point, direction);
+        let ahead = |target: Point| match direction {
+            Direction::North => {
+                (target.x == point.x && target.y < point.y).then(|| point.y - target.y)
+            }
+            Direction::South => {
+                (target.x == point.x && target.y > point.y).then(|| target.y - point.y)
+            }
+            Direction::West => {
+                (target.y == point.y && target.x < point.x).then(|| point.x - target.x)
+            }
+            Direction::East => {
+                (target.y == point.y && target.x > point.x).then(|| target.x - point.x)
+            }
+            _ => panic!("{:?} is not cardinal", direction),
+        };
+
+        match (ahead(extra), next.and_then(ahead)) {
+            (Some(extra_distance), Some(distance)) if extra_distance < distance => Some(extra),
+            (Some(_), None) => Some(extra),
+            _ => next,
+        }
+    }
+
+    // Where walking from `point` ends up, right before the blocker, or `None` off the edge.
+    pub fn stop(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.next(point, direction)
+            .map(|blocker| blocker.go(direction.reverse()))
+    }
+}
+
+#[cfg(test)]
+mod ray_tests {
+    use super::*;
+
+    fn map() -> Grid<bool> {
+        Grid::parse("..#..\n.....\n#...#\n.....\n..#..\n", |byte, _| {
+            Some(byte == b'#')
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn rays() {
+        let map = map();
+        let points: Vec<Point> = map.ray(Point::new(2, 2), Direction::East).collect();
+        assert_eq!(points, vec![Point::new(3, 2), Point::new(4, 2)]);
+        assert_eq!(
+            map.ray(Point::new(0, 0), IPoint::new(2, 1))
+                .collect::<Vec<_>>(),
+            vec![Point::new(2, 1), Point::new(4, 2)]
+        );
+        assert_eq!(map.ray(Point::new(0, 0), Direction::North).count(), 0);
+        assert_eq!(
+            map.first_hit(Point::new(2, 2), Direction::South, |cell| *cell),
+            Some(Point::new(2, 4))
+        );
+        assert_eq!(
+            map.first_hit(Point::new(1, 1), Direction::SouthEast, |cell| *cell),
+            None
+        );
+    }
+
+    #[test]
+    fn blockers() {
+        let map = map();
+        let blockers = map.blockers(|cell| *cell);
+        let centre = Point::new(2, 2);
+        for direction in CARDINALS {
+            assert_eq!(
+                blockers.next(centre, direction),
+                map.first_hit(centre, direction, |cell| *cell)
+            );
+        }
+        assert_eq!(blockers.next(Point::new(1, 1), Direction::North), None);
+        assert_eq!(
+            blockers.stop(Point::new(2, 3), Direction::North),
+            Some(Point::new(2, 1))
+        );
+
+        let extra = Point::new(2, 1);
+        assert_eq!(
+            blockers.next_with(centre, Direction::North, extra),
+            Some(extra)
+        );
+        assert_eq!(
+            blockers.next_with(Point::new(2, 1), Direction::South, Point::new(2, 0)),
+            Some(Point::new(2, 4))
+        );
+        assert_eq!(
+            blockers.next_with(Point::new(1, 1), Direction::East, Point::new(3, 1)),
+            Some(Point::new(3, 1))
+        );
+    }
+    #[test]
+    #[should_panic(expected = "is not cardinal")]
+    fn ordinal_blockers() {
+        let blockers = map().blockers(|cell| *cell);
+        blockers.next_with(Point::new(1, 1), Direction::SouthEast, Point::new(3, 3));
+    }
+}
@@ -0,0 +1,397 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Index, IndexMut},
+};
+
+use fxhash::FxHashMap as HashMap;
+
+use crate::points::{
+    directions::{Direction, CARDINALS, DIRECTIONS, ORDINALS},
+    ipoint::IPoint,
+    point::Point,
+};
+
+use super::Grid;
+
+// An unbounded grid that only stores the cells that were set, for coordinates that can go
+// negative or grow without limit. The bounding box always covers exactly the stored cells, it
+// comes from counting the cells in each column and row so removing one never needs a rescan.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<IPoint, T>,
+    columns: BTreeMap<isize, usize>,
+    rows: BTreeMap<isize, usize>,
+}
+
+fn count(lines: &mut BTreeMap<isize, usize>, line: isize) {
+    *lines.entry(line).or_default() += 1;
+}
+
+fn uncount(lines: &mut BTreeMap<isize, usize>, line: isize) {
+    if let Some(count) = lines.get_mut(&line) {
+        *count -= 1;
+        if *count == 0 {
+            lines.remove(&line);
+        }
+    }
+}
+
+fn span(lines: &BTreeMap<isize, usize>) -> Option<(isize, isize)> {
+    Some((*lines.first_key_value()?.0, *lines.last_key_value()?.0))
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::default(),
+            columns: BTreeMap::new(),
+            rows: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // The smallest and largest corner of the occupied cells, both inclusive.
+    pub fn bounds(&self) -> Option<(IPoint, IPoint)> {
+        let ((min_x, max_x), (min_y, max_y)) = (span(&self.columns)?, span(&self.rows)?);
+        Some((IPoint::new(min_x, min_y), IPoint::new(max_x, max_y)))
+    }
+
+    pub fn width(&self) -> usize {
+        span(&self.columns).map_or(0, |(min, max)| (max - min) as usize + 1)
+    }
+
+    pub fn height(&self) -> usize {
+        span(&self.rows).map_or(0, |(min, max)| (max - min) as usize + 1)
+    }
+
+    pub fn contains(&self, point: IPoint) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: IPoint) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: IPoint) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn insert(&mut self, point: IPoint, value: T) -> Option<T> {
+        let previous = self.cells.insert(point, value);
+        if previous.is_none() {
+            count(&mut self.columns, point.x);
+            count(&mut self.rows, point.y);
+        }
+        previous
+    }
+
+    pub fn remove(&mut self, point: IPoint) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+        uncount(&mut self.columns, point.x);
+        uncount(&mut self.rows, point.y);
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.columns.clear();
+        self.rows.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (IPoint, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = IPoint> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn go(&self, point: IPoint, direction: Direction) -> IPoint {
+        point.go(direction)
+    }
+
+    // Neighbours are yielded whether or not they are stored, there is no edge to stop at.
+    pub fn adjacent_four(&self, point: IPoint) -> impl Iterator<Item = IPoint> {
+        self.neighbours(point, CARDINALS).map(|(point, _)| point)
+    }
+
+    pub fn adjacent_eight(&self, point: IPoint) -> impl Iterator<Item = IPoint> {
+        self.neighbours(point, DIRECTIONS).map(|(point, _)| point)
+    }
+
+    pub fn diagonals(&self, point: IPoint) -> impl Iterator<Item = IPoint> {
+        self.neighbours(point, ORDINALS).map(|(point, _)| point)
+    }
+
+    pub fn adjacent_four_directional(&self, point: IPoint) -> SparseAdjacent<4> {
+        self.neighbours(point, CARDINALS)
+    }
+
+    pub fn adjacent_three_in_direction(&self, point: IPoint, from: Direction) -> SparseAdjacent<3> {
+        let back = from.reverse();
+        self.neighbours(point, [back.rotate_90(), from, back.rotate_counter_90()])
+    }
+
+    // Only the neighbours that hold a value, along with it.
+    pub fn occupied_neighbours(&self, point: IPoint) -> impl Iterator<Item = (IPoint, &T)> {
+        self.adjacent_eight(point)
+            .filter_map(|point| self.get(point).map(|value| (point, value)))
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        point: IPoint,
+        directions: [Direction; N],
+    ) -> SparseAdjacent<N> {
+        SparseAdjacent {
+            point,
+            directions: directions.into_iter(),
+        }
+    }
+
+    // Lays the cells out densely over the bounding box, whose smallest corner becomes (0,0).
+    pub fn to_grid_with<U>(&self, mut f: impl FnMut(Option<&T>) -> U) -> Grid<U> {
+        let Some((min, max)) = self.bounds() else {
+            return Grid::new(Vec::new(), 0, 0);
+        };
+
+        let mut vec = Vec::with_capacity(self.width() * self.height());
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                vec.push(f(self.get(IPoint::new(x, y))));
+            }
+        }
+        Grid::new(vec, self.width(), self.height())
+    }
+
+    // Labels are the signed coordinates, columns widen to fit them.
+    fn print_with(&self, unit_width: usize, f: impl Fn(Option<&T>) -> String) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        let label = |min: isize, max: isize| min.to_string().len().max(max.to_string().len());
+        let (label_width, unit_width) = (label(min.y, max.y), label(min.x, max.x).max(unit_width));
+
+        let mut str = String::new();
+        for y in min.y..=max.y {
+            str.push_str(&format!("{:>label_width$} ", y));
+            for x in min.x..=max.x {
+                str.push_str(&format!(
+                    "{:width$} ",
+                    f(self.get(IPoint::new(x, y))),
+                    width = unit_width,
+                ));
+            }
+            str.push('\n');
+        }
+        str.push_str(&" ".repeat(label_width + 1));
+        for x in min.x..=max.x {
+            str.push_str(&format!("{:<width$} ", x, width = unit_width));
+        }
+
+        str.push('\n');
+        str
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Clone,
+{
+    pub fn to_grid(&self) -> Grid<Option<T>> {
+        self.to_grid_with(|value| value.cloned())
+    }
+
+    pub fn to_grid_or(&self, fill: T) -> Grid<T> {
+        self.to_grid_with(|value| value.unwrap_or(&fill).clone())
+    }
+}
+
+pub struct SparseAdjacent<const N: usize> {
+    point: IPoint,
+    directions: std::array::IntoIter<Direction, N>,
+}
+
+impl<const N: usize> Iterator for SparseAdjacent<N> {
+    type Item = (IPoint, Direction);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let direction = self.directions.next()?;
+        Some((self.point.go(direction), direction))
+    }
+}
+
+impl<T> FromIterator<(IPoint, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (IPoint, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(IPoint, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (IPoint, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let width = grid.width;
+        grid.vec
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| (IPoint::from(Point::new(i % width, i / width)), value))
+            .collect()
+    }
+}
+
+impl<T> Index<IPoint> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: IPoint) -> &T {
+        self.cells
+            .get(&index)
+            .unwrap_or_else(|| panic!("No cell at {}", index))
+    }
+}
+
+impl<T> IndexMut<IPoint> for SparseGrid<T> {
+    fn index_mut(&mut self, index: IPoint) -> &mut T {
+        self.cells
+            .get_mut(&index)
+            .unwrap_or_else(|| panic!("No cell at {}", index))
+    }
+}
+
+// Cells that were never set print as '.', like `None` does for the dense grid.
+impl SparseGrid<usize> {
+    pub fn print_usize(&self, unit_width: usize) -> String {
+        self.print_with(unit_width, |value| {
+            value.map_or(".".to_string(), |value| value.to_string())
+        })
+    }
+}
+
+impl SparseGrid<u8> {
+    pub fn print_u8(&self, unit_width: usize) -> String {
+        self.print_with(unit_width, |value| {
+            value.map_or(".".to_string(), |value| value.to_string())
+        })
+    }
+}
+
+impl SparseGrid<bool> {
+    pub fn print_bool(&self) -> String {
+        self.print_with(1, |value| {
+            if value == Some(&true) { "■" } else { "." }.to_string()
+        })
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: std::fmt::Debug,
+{
+    pub fn print_debug(&self, unit_width: usize) -> String {
+        self.print_with(unit_width, |value| {
+            value.map_or(".".to_string(), |value| format!("{:?}", value))
+        })
+    }
+}
+
+#[cfg(test)]
+mod sparse_tests {
+    use super::*;
+
+    fn sparse() -> SparseGrid<bool> {
+        [
+            (IPoint::new(-2, -1), true),
+            (IPoint::new(1, 0), true),
+            (IPoint::new(0, 1), false),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn bounds_follow_cells() {
+        let mut grid = sparse();
+        assert_eq!(
+            grid.bounds(),
+            Some((IPoint::new(-2, -1), IPoint::new(1, 1)))
+        );
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+
+        // Overwriting a cell doesn't count it twice.
+        grid.insert(IPoint::new(-2, -1), false);
+        grid.remove(IPoint::new(-2, -1));
+        assert_eq!(grid.bounds(), Some((IPoint::new(0, 0), IPoint::new(1, 1))));
+        grid.remove(IPoint::new(1, 0));
+        grid.remove(IPoint::new(0, 1));
+        assert_eq!(grid.bounds(), None);
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+    }
+
+    #[test]
+    fn neighbours_cross_zero() {
+        let grid = sparse();
+        let four: Vec<IPoint> = grid.adjacent_four(IPoint::new(0, 0)).collect();
+        assert_eq!(
+            four,
+            vec![
+                IPoint::new(0, -1),
+                IPoint::new(1, 0),
+                IPoint::new(0, 1),
+                IPoint::new(-1, 0)
+            ]
+        );
+        assert_eq!(
+            grid.go(IPoint::new(0, 0), Direction::NorthWest),
+            IPoint::new(-1, -1)
+        );
+        assert_eq!(grid.occupied_neighbours(IPoint::new(0, 0)).count(), 2);
+    }
+
+    #[test]
+    fn dense() {
+        let grid = sparse();
+        let dense = grid.to_grid_or(false);
+        assert_eq!((dense.width, dense.height), (4, 3));
+        assert_eq!(dense.row(0), &[true, false, false, false]);
+        assert_eq!(dense.row(1), &[false, false, false, true]);
+        assert_eq!(grid.to_grid()[(2, 2)], Some(false));
+        assert_eq!(grid.to_grid()[(0, 2)], None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let grid = Grid::new(vec![1u8, 2, 3, 4, 5, 6], 3, 2);
+        let sparse = SparseGrid::from(grid.clone());
+        assert_eq!(sparse[IPoint::new(2, 1)], 6);
+        assert_eq!(sparse.to_grid_or(0).vec, grid.vec);
+    }
+
+    #[test]
+    fn print() {
+        assert_eq!(
+            sparse().print_bool(),
+            "-1 ■  .  .  .  \n 0 .  .  .  ■  \n 1 .  .  .  .  \n   -2 -1 0  1  \n"
+        );
+    }
+}
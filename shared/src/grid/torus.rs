@@ -0,0 +1,220 @@
+use std::ops::{Index, IndexMut};
+
+use crate::points::{
+    directions::{Direction, CARDINALS, DIRECTIONS, ORDINALS},
+    ipoint::IPoint,
+    point::Point,
+    traits::ModuloPositive,
+};
+
+use super::{iterators::Positions, Grid};
+
+// A grid whose edges wrap around, so moving off one side comes back in on the other and every
+// cell has all of its neighbours. Only the `Grid` methods that never cross an edge are forwarded,
+// `as_grid` lends out the plain grid for anything else.
+#[derive(Clone, Debug)]
+pub struct Torus<T> {
+    grid: Grid<T>,
+}
+
+impl<T> From<Grid<T>> for Torus<T> {
+    fn from(grid: Grid<T>) -> Self {
+        Self { grid }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn wrapping(self) -> Torus<T> {
+        Torus::from(self)
+    }
+}
+
+impl<T> Torus<T> {
+    pub fn into_inner(self) -> Grid<T> {
+        self.grid
+    }
+
+    pub fn as_grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        self.grid.row(y)
+    }
+
+    pub fn points(&self) -> Positions {
+        self.grid.points()
+    }
+
+    pub fn wrap(&self, point: IPoint) -> Point {
+        let point = point.modulo_positive(IPoint::new(
+            self.grid.width as isize,
+            self.grid.height as isize,
+        ));
+        Point::new(point.x as usize, point.y as usize)
+    }
+
+    pub fn go(&self, point: Point, direction: Direction) -> Point {
+        self.wrap(IPoint::from(point).go(direction))
+    }
+
+    pub fn adjacent_four(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, CARDINALS).map(|(point, _)| point)
+    }
+
+    pub fn adjacent_eight(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, DIRECTIONS).map(|(point, _)| point)
+    }
+
+    pub fn diagonals(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, ORDINALS).map(|(point, _)| point)
+    }
+
+    pub fn adjacent_four_directional(&self, point: Point) -> WrappingAdjacent<4> {
+        self.neighbours(point, CARDINALS)
+    }
+
+    // Every cardinal neighbour except the one `from` points back to, like `Grid`'s version.
+    pub fn adjacent_three_in_direction(
+        &self,
+        point: Point,
+        from: Direction,
+    ) -> WrappingAdjacent<3> {
+        let back = from.reverse();
+        self.neighbours(point, [back.rotate_90(), from, back.rotate_counter_90()])
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        point: Point,
+        directions: [Direction; N],
+    ) -> WrappingAdjacent<N> {
+        WrappingAdjacent {
+            point: IPoint::from(point),
+            dimensions: IPoint::new(self.grid.width as isize, self.grid.height as isize),
+            directions: directions.into_iter(),
+        }
+    }
+}
+
+pub struct WrappingAdjacent<const N: usize> {
+    point: IPoint,
+    dimensions: IPoint,
+    directions: std::array::IntoIter<Direction, N>,
+}
+
+impl<const N: usize> Iterator for WrappingAdjacent<N> {
+    type Item = (Point, Direction);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let direction = self.directions.next()?;
+        let point = self.point.go(direction).modulo_positive(self.dimensions);
+        Some((Point::new(point.x as usize, point.y as usize), direction))
+    }
+}
+
+impl<T> Index<IPoint> for Torus<T> {
+    type Output = T;
+
+    fn index(&self, index: IPoint) -> &T {
+        &self.grid[self.wrap(index)]
+    }
+}
+
+impl<T> IndexMut<IPoint> for Torus<T> {
+    fn index_mut(&mut self, index: IPoint) -> &mut T {
+        let point = self.wrap(index);
+        &mut self.grid[point]
+    }
+}
+
+impl<T> Index<Point> for Torus<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &T {
+        &self.grid[(index.x % self.grid.width, index.y % self.grid.height)]
+    }
+}
+
+impl<T> IndexMut<Point> for Torus<T> {
+    fn index_mut(&mut self, index: Point) -> &mut T {
+        let (width, height) = (self.grid.width, self.grid.height);
+        &mut self.grid[(index.x % width, index.y % height)]
+    }
+}
+
+#[cfg(test)]
+mod torus_tests {
+    use super::*;
+
+    fn torus() -> Torus<u8> {
+        Grid::new((0..12).collect(), 4, 3).wrapping()
+    }
+
+    #[test]
+    fn go_across_edges() {
+        let torus = torus();
+        assert_eq!(
+            torus.go(Point::new(0, 0), Direction::West),
+            Point::new(3, 0)
+        );
+        assert_eq!(
+            torus.go(Point::new(0, 0), Direction::North),
+            Point::new(0, 2)
+        );
+        assert_eq!(
+            torus.go(Point::new(3, 2), Direction::SouthEast),
+            Point::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn neighbours_of_a_corner() {
+        let torus = torus();
+        let four: Vec<Point> = torus.adjacent_four(Point::new(0, 0)).collect();
+        assert_eq!(
+            four,
+            vec![
+                Point::new(0, 2),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(3, 0)
+            ]
+        );
+        assert_eq!(torus.adjacent_eight(Point::new(0, 0)).count(), 8);
+        assert_eq!(torus.diagonals(Point::new(3, 2)).count(), 4);
+    }
+
+    #[test]
+    fn three_in_direction() {
+        let torus = torus();
+        let directions: Vec<Direction> = torus
+            .adjacent_three_in_direction(Point::new(0, 0), Direction::East)
+            .map(|(_, direction)| direction)
+            .collect();
+        assert_eq!(
+            directions,
+            vec![Direction::North, Direction::East, Direction::South]
+        );
+    }
+
+    #[test]
+    fn index_modulo_size() {
+        let mut torus = torus();
+        assert_eq!(torus[IPoint::new(-1, -1)], 11);
+        assert_eq!(torus[IPoint::new(5, 3)], 1);
+        assert_eq!(torus[Point::new(4, 4)], 4);
+        torus[IPoint::new(-4, 0)] = 42;
+        assert_eq!(torus.row(0), &[42, 1, 2, 3]);
+        assert_eq!((torus.width(), torus.height()), (4, 3));
+        assert_eq!(torus.points().count(), 12);
+    }
+}
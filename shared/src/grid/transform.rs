@@ -0,0 +1,144 @@
+use crate::points::point::Point;
+
+use super::Grid;
+
+// Owned copies of the grid turned, mirrored, repeated or blown up. Rotations go clockwise, like
+// `Direction::rotate_90` does.
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    // Builds a grid by looking up where each of its cells comes from in this one.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self {
+        let mut vec = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                vec.push(self[source(Point::new(x, y))].clone());
+            }
+        }
+        Grid::new(vec, width, height)
+    }
+
+    pub fn rotate_90(&self) -> Self {
+        let height = self.height;
+        self.remap(self.height, self.width, |point| {
+            Point::new(point.y, height - 1 - point.x)
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let mut grid = self.clone();
+        grid.vec.reverse();
+        grid
+    }
+
+    pub fn rotate_270(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, self.width, |point| {
+            Point::new(width - 1 - point.y, point.x)
+        })
+    }
+
+    // Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let mut grid = self.clone();
+        for row in grid.vec.chunks_exact_mut(self.width.max(1)) {
+            row.reverse();
+        }
+        grid
+    }
+
+    // Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let mut vec = Vec::with_capacity(self.vec.len());
+        for y in (0..self.height).rev() {
+            vec.extend_from_slice(self.row(y));
+        }
+        Grid::new(vec, self.width, self.height)
+    }
+
+    // The grid repeated `across` times side by side and `down` times on top of each other.
+    pub fn tile(&self, across: usize, down: usize) -> Self {
+        let mut vec = Vec::with_capacity(self.vec.len() * across * down);
+        for _ in 0..down {
+            for y in 0..self.height {
+                for _ in 0..across {
+                    vec.extend_from_slice(self.row(y));
+                }
+            }
+        }
+        Grid::new(vec, self.width * across, self.height * down)
+    }
+
+    pub fn scale(&self, factor: usize) -> Self {
+        self.stretch(factor, factor, |cell, _| cell.clone())
+    }
+}
+
+impl<T> Grid<T> {
+    // Every cell becomes a block of `wide` by `high` cells, each made by `f` from the original
+    // cell and where in the block it sits.
+    pub fn stretch<U>(&self, wide: usize, high: usize, f: impl Fn(&T, Point) -> U) -> Grid<U> {
+        let mut vec = Vec::with_capacity(self.vec.len() * wide * high);
+        for y in 0..self.height {
+            for dy in 0..high {
+                for cell in self.row(y) {
+                    vec.extend((0..wide).map(|dx| f(cell, Point::new(dx, dy))));
+                }
+            }
+        }
+        Grid::new(vec, self.width * wide, self.height * high)
+    }
+}
+
+#[cfg(test)]
+mod transform_tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        // 0 1 2
+        // 3 4 5
+        Grid::new((0..6).collect(), 3, 2)
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = grid();
+        let quarter = grid.rotate_90();
+        assert_eq!((quarter.width, quarter.height), (2, 3));
+        assert_eq!(quarter.vec, vec![3, 0, 4, 1, 5, 2]);
+        assert_eq!(grid.rotate_180().vec, vec![5, 4, 3, 2, 1, 0]);
+        assert_eq!(grid.rotate_270().vec, vec![2, 5, 1, 4, 0, 3]);
+        assert_eq!(quarter.rotate_90().vec, grid.rotate_180().vec);
+        assert_eq!(quarter.rotate_270().vec, grid.vec);
+    }
+
+    #[test]
+    fn flips() {
+        let grid = grid();
+        assert_eq!(grid.flip_horizontal().vec, vec![2, 1, 0, 5, 4, 3]);
+        assert_eq!(grid.flip_vertical().vec, vec![3, 4, 5, 0, 1, 2]);
+        assert_eq!(
+            grid.flip_horizontal().flip_vertical().vec,
+            grid.rotate_180().vec
+        );
+    }
+
+    #[test]
+    fn tile() {
+        let tiled = grid().tile(2, 2);
+        assert_eq!((tiled.width, tiled.height), (6, 4));
+        assert_eq!(tiled.row(1), &[3, 4, 5, 3, 4, 5]);
+        assert_eq!(tiled.row(2), &[0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn stretch() {
+        let scaled = grid().scale(2);
+        assert_eq!((scaled.width, scaled.height), (6, 4));
+        assert_eq!(scaled.row(3), &[3, 3, 4, 4, 5, 5]);
+
+        let halves = grid().stretch(2, 1, |cell, offset| (*cell, offset.x));
+        assert_eq!(halves.row(0)[2..4], [(1, 0), (1, 1)]);
+    }
+}
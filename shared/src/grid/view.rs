@@ -0,0 +1,198 @@
+use std::{
+    iter::StepBy,
+    ops::{Index, Range},
+    slice::Iter,
+};
+
+use crate::points::point::Point;
+
+use super::{iterators::Positions, Grid};
+
+// Borrowed looks at part of a grid, or all of it turned on its side, without copying cells.
+impl<T> Grid<T> {
+    // The cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(x < self.width, "Column {} is off the grid", x);
+        self.vec[x..].iter().step_by(self.width)
+    }
+
+    pub fn window(&self, origin: Point, width: usize, height: usize) -> Window<'_, T> {
+        assert!(
+            origin.x + width <= self.width && origin.y + height <= self.height,
+            "A {}x{} window at {} doesn't fit",
+            width,
+            height,
+            origin
+        );
+        Window {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    pub fn transpose(&self) -> Transposed<'_, T> {
+        Transposed {
+            grid: self,
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+pub struct Window<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> Window<'a, T> {
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    pub fn is_within_bounds(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    // Points are relative to the window's own top left corner.
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        self.is_within_bounds(point)
+            .then(|| &self.grid[self.origin + point])
+    }
+
+    fn columns(&self) -> Range<usize> {
+        self.origin.x..self.origin.x + self.width
+    }
+
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height, "Row {} is off the window", y);
+        &self.grid.row(self.origin.y + y)[self.columns()]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.rows().flatten()
+    }
+
+    pub fn points(&self) -> Positions {
+        Positions {
+            height: self.height,
+            width: self.width,
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
+impl<T> Window<'_, T>
+where
+    T: Clone,
+{
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::new(self.iter().cloned().collect(), self.width, self.height)
+    }
+}
+
+impl<T> Index<Point> for Window<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &T {
+        self.get(index)
+            .unwrap_or_else(|| panic!("{} is off the window", index))
+    }
+}
+
+// The grid mirrored along its main diagonal, so rows become columns.
+pub struct Transposed<'a, T> {
+    grid: &'a Grid<T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> Transposed<'a, T> {
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        (point.x < self.width && point.y < self.height).then(|| &self.grid[(point.y, point.x)])
+    }
+
+    pub fn row(&self, y: usize) -> StepBy<Iter<'a, T>> {
+        self.grid.column(y)
+    }
+
+    pub fn column(&self, x: usize) -> &'a [T] {
+        self.grid.row(x)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.height).flat_map(|y| self.row(y))
+    }
+}
+
+impl<T> Transposed<'_, T>
+where
+    T: Clone,
+{
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::new(self.iter().cloned().collect(), self.width, self.height)
+    }
+}
+
+impl<T> Index<Point> for Transposed<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &T {
+        &self.grid[(index.y, index.x)]
+    }
+}
+
+#[cfg(test)]
+mod view_tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::new((0..12).collect(), 4, 3)
+    }
+
+    #[test]
+    fn columns() {
+        let grid = grid();
+        assert!(grid.column(1).eq(&[1, 5, 9]));
+        assert!(grid.column(3).rev().eq(&[11, 7, 3]));
+    }
+
+    #[test]
+    fn window() {
+        let grid = grid();
+        let window = grid.window(Point::new(1, 1), 3, 2);
+        assert_eq!(window[Point::new(0, 0)], 5);
+        assert_eq!(window.get(Point::new(3, 0)), None);
+        assert_eq!(window.row(1), &[9, 10, 11]);
+        assert_eq!(window.to_grid().vec, vec![5, 6, 7, 9, 10, 11]);
+        assert_eq!(window.points().count(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn window_overhanging() {
+        grid().window(Point::new(2, 2), 3, 1);
+    }
+
+    #[test]
+    fn transpose() {
+        let grid = grid();
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width, transposed.height), (3, 4));
+        assert_eq!(transposed[Point::new(2, 1)], 9);
+        assert!(transposed.row(1).eq(&[1, 5, 9]));
+        assert_eq!(transposed.column(2), &[8, 9, 10, 11]);
+        assert_eq!(
+            transposed.to_grid().vec,
+            vec![0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11]
+        );
+    }
+}
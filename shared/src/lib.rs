@@ -81,51 +81,69 @@ impl<T> From<Option<T>> for Solution {
     }
 }
 
-use colored::Colorize;
+use colored::{ColoredString, Colorize};
 use points::point::Point;
 
 pub fn execute(f: &dyn Fn(&str) -> Solution, input: &str, day: &str, name: &str) -> Duration {
-    let start = Instant::now();
-    let result = f(input);
-    let time = start.elapsed();
+    let (result, time) = measure(f, input);
 
     if let Solution::None = result {
         return Duration::ZERO;
     }
 
-    let ratio = time.as_micros() as f64 / (Duration::from_secs(1) / 50).as_micros() as f64;
+    report(&result, time, day, name);
 
-    let color = (ratio * 255.0).min(255.0) as u8;
+    time
+}
 
+pub fn measure(f: &dyn Fn(&str) -> Solution, input: &str) -> (Solution, Duration) {
+    let start = Instant::now();
+    let result = f(input);
+    (result, start.elapsed())
+}
+
+pub fn report(result: &Solution, time: Duration, day: &str, name: &str) {
     println!(
         "{: >12} {:} => {}",
-        format!("{:#?}", time).truecolor(color, 255 - color, 0),
+        colored_time(time, PART_BUDGET),
         format!("{}: {}", day, name).cyan().bold(),
         format!("{}", result).bold(),
     );
-
-    time
 }
 
 pub fn total(time: Duration) {
-    let ratio = time.as_micros() as f64 / (Duration::from_secs(1)).as_micros() as f64;
+    let (red, green, blue) = time_color(time, TOTAL_BUDGET);
 
-    let color = (ratio * 255.0).min(255.0) as u8;
-
-    let remaining = Duration::from_secs(1) - time;
+    let remaining = TOTAL_BUDGET - time;
 
     println!(
         "{: >12} {}",
-        format!("{:#?}", time).truecolor(color, 255 - color, 0),
+        format!("{:#?}", time).truecolor(red, green, blue),
         "Total".cyan().bold(),
     );
     println!(
         "{: >12} {}",
-        format!("{:#?}", remaining).truecolor(color, 255 - color, 0),
+        format!("{:#?}", remaining).truecolor(red, green, blue),
         "Remaining".cyan().bold(),
     );
 }
 
+pub const TOTAL_BUDGET: Duration = Duration::from_secs(1);
+pub const PART_BUDGET: Duration = Duration::from_millis(20);
+
+pub fn time_color(time: Duration, budget: Duration) -> (u8, u8, u8) {
+    let ratio = time.as_micros() as f64 / budget.as_micros() as f64;
+
+    let color = (ratio * 255.0).min(255.0) as u8;
+
+    (color, 255 - color, 0)
+}
+
+pub fn colored_time(time: Duration, budget: Duration) -> ColoredString {
+    let (red, green, blue) = time_color(time, budget);
+    format!("{:#?}", time).truecolor(red, green, blue)
+}
+
 pub fn day_name(day: u32) -> &'static str {
     match day {
         1 => "Historian Hysteria",
@@ -155,8 +173,10 @@ pub fn day_name(day: u32) -> &'static str {
     }
 }
 
+pub mod examples;
 pub mod grid;
 pub mod math;
 pub mod misc;
 pub mod parse;
 pub mod points;
+pub mod search;
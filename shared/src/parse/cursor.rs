@@ -0,0 +1,362 @@
+use super::{ParseError, PositionedError, ToDigit};
+
+pub trait FromDigits: Sized + Copy {
+    const ZERO: Self;
+    const SIGNED: bool;
+
+    // Negative numbers accumulate downwards so that the type's minimum is reachable.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! from_digits {
+    ($type:ident) => {
+        impl FromDigits for $type {
+            const ZERO: Self = 0;
+            const SIGNED: bool = false;
+
+            fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                if negative {
+                    return None;
+                }
+                self.checked_mul(10)?.checked_add(digit as $type)
+            }
+        }
+    };
+}
+
+macro_rules! from_digits_signed {
+    ($type:ident) => {
+        impl FromDigits for $type {
+            const ZERO: Self = 0;
+            const SIGNED: bool = true;
+
+            fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                if negative {
+                    return self.checked_mul(10)?.checked_sub(digit as $type);
+                }
+                self.checked_mul(10)?.checked_add(digit as $type)
+            }
+        }
+    };
+}
+
+from_digits!(u8);
+from_digits!(u16);
+from_digits!(u32);
+from_digits!(u64);
+from_digits!(u128);
+from_digits!(usize);
+from_digits_signed!(i8);
+from_digits_signed!(i16);
+from_digits_signed!(i32);
+from_digits_signed!(i64);
+from_digits_signed!(i128);
+from_digits_signed!(isize);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Checkpoint(usize);
+
+#[derive(Clone, Debug)]
+pub struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.position.min(self.bytes.len())..]
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    pub fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.position + offset).copied()
+    }
+
+    pub fn next_byte(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    pub fn bytes(&mut self) -> Bytes<'_, 'a> {
+        Bytes { cursor: self }
+    }
+
+    pub fn advance(&mut self, count: usize) {
+        self.position = (self.position + count).min(self.bytes.len());
+    }
+
+    pub fn save(&self) -> Checkpoint {
+        Checkpoint(self.position)
+    }
+
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.position = checkpoint.0;
+    }
+
+    pub fn expect_byte(&mut self, expected: u8) -> Result<(), PositionedError> {
+        match self.peek() {
+            Some(byte) if byte == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            found => Err(self.error(ParseError::Expected {
+                expected: (expected as char).to_string(),
+                found,
+            })),
+        }
+    }
+
+    // Leaves the cursor where it was when the literal doesn't match.
+    pub fn expect_literal(&mut self, literal: &str) -> Result<(), PositionedError> {
+        if self.remaining().starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            return Ok(());
+        }
+
+        let mismatch = self
+            .remaining()
+            .iter()
+            .zip(literal.bytes())
+            .take_while(|(a, b)| *a == b)
+            .count();
+
+        Err(PositionedError::new(
+            self.bytes,
+            self.position + mismatch,
+            ParseError::Expected {
+                expected: literal.to_owned(),
+                found: self.peek_at(mismatch),
+            },
+        ))
+    }
+
+    pub fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'a [u8] {
+        let start = self.position;
+        let length = self
+            .remaining()
+            .iter()
+            .take_while(|byte| predicate(**byte))
+            .count();
+        self.position += length;
+        &self.bytes[start..self.position]
+    }
+
+    // Stops on the first matching byte without consuming it, returns false when none was found.
+    pub fn skip_until(&mut self, predicate: impl Fn(u8) -> bool) -> bool {
+        match self.remaining().iter().position(|byte| predicate(*byte)) {
+            Some(offset) => {
+                self.position += offset;
+                true
+            }
+            None => {
+                self.position = self.bytes.len();
+                false
+            }
+        }
+    }
+
+    pub fn number<T: FromDigits>(&mut self) -> Result<T, PositionedError> {
+        self.number_with_max_digits(usize::MAX)
+    }
+
+    pub fn number_with_max_digits<T: FromDigits>(
+        &mut self,
+        max: usize,
+    ) -> Result<T, PositionedError> {
+        self.digits(self.position, max, false)
+    }
+
+    // Accepts a leading `+`, and a leading `-` when the target type is signed.
+    pub fn signed_number<T: FromDigits>(&mut self) -> Result<T, PositionedError> {
+        let start = self.position;
+        let negative = match self.peek() {
+            Some(b'-') if T::SIGNED => true,
+            Some(b'+') => false,
+            _ => return self.digits(start, usize::MAX, false),
+        };
+
+        self.position += 1;
+        self.digits(start, usize::MAX, negative)
+    }
+
+    fn digits<T: FromDigits>(
+        &mut self,
+        start: usize,
+        max: usize,
+        negative: bool,
+    ) -> Result<T, PositionedError> {
+        let first = self.position;
+        let mut value = T::ZERO;
+        while let Some(digit) = self.peek().and_then(|byte| byte.to_digit()) {
+            if self.position - first >= max {
+                return Err(self.error(ParseError::TooManyDigits(max)));
+            }
+
+            value = match value.push_digit(digit, negative) {
+                Some(value) => value,
+                None => {
+                    self.take_while(|byte| byte.is_ascii_digit());
+                    let digits = &self.bytes[start..self.position];
+                    return Err(PositionedError::new(
+                        self.bytes,
+                        start,
+                        ParseError::Overflow(String::from_utf8_lossy(digits).into_owned()),
+                    ));
+                }
+            };
+            self.position += 1;
+        }
+
+        if self.position == first {
+            return Err(self.error(ParseError::Expected {
+                expected: "digit".to_owned(),
+                found: self.peek(),
+            }));
+        }
+
+        Ok(value)
+    }
+
+    pub fn error(&self, error: ParseError) -> PositionedError {
+        PositionedError::new(self.bytes, self.position, error)
+    }
+}
+
+// Lets the iterator based parsers such as `Parsable` continue from the cursor's position.
+pub struct Bytes<'c, 'a> {
+    cursor: &'c mut Cursor<'a>,
+}
+
+impl Iterator for Bytes<'_, '_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next_byte()
+    }
+}
+
+#[cfg(test)]
+mod cursor_tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn literals_and_numbers() {
+        let mut cursor = Cursor::new(b"mul(12,345)");
+        assert_eq!(cursor.expect_literal("mul("), Ok(()));
+        assert_eq!(cursor.number_with_max_digits::<usize>(3), Ok(12));
+        assert_eq!(cursor.expect_byte(b','), Ok(()));
+        assert_eq!(cursor.number_with_max_digits::<usize>(3), Ok(345));
+        assert_eq!(cursor.expect_byte(b')'), Ok(()));
+        assert!(cursor.is_at_end());
+    }
+
+    #[test_case("mul(1234,5)", ParseError::TooManyDigits(3), 7)]
+    #[test_case("mul(,5)", ParseError::Expected { expected: "digit".to_owned(), found: Some(b',') }, 4)]
+    #[test_case("mul(", ParseError::Expected { expected: "digit".to_owned(), found: None }, 4)]
+    fn number_errors(input: &str, error: ParseError, offset: usize) {
+        let mut cursor = Cursor::new(input.as_bytes());
+        cursor.expect_literal("mul(").unwrap();
+        let result = cursor.number_with_max_digits::<usize>(3);
+        assert_eq!(
+            result.map_err(|e| (e.error, e.offset)),
+            Err((error, offset))
+        );
+    }
+
+    #[test]
+    fn number_overflow() {
+        let mut cursor = Cursor::new(b"x 256 y");
+        cursor.advance(2);
+        let error = cursor.number::<u8>().unwrap_err();
+        assert_eq!(error.error, ParseError::Overflow("256".to_owned()));
+        assert_eq!(error.offset, 2);
+        assert_eq!(cursor.peek(), Some(b' '));
+    }
+
+    #[test_case("-128", Ok(i8::MIN))]
+    #[test_case("+127", Ok(i8::MAX))]
+    #[test_case("-129", Err(ParseError::Overflow("-129".to_owned())))]
+    #[test_case("-", Err(ParseError::Expected { expected: "digit".to_owned(), found: None }))]
+    fn signed_numbers(input: &str, expected: Result<i8, ParseError>) {
+        let mut cursor = Cursor::new(input.as_bytes());
+        assert_eq!(cursor.signed_number().map_err(|e| e.error), expected);
+    }
+
+    #[test]
+    fn signed_number_into_unsigned() {
+        let mut cursor = Cursor::new(b"-3");
+        let error = cursor.signed_number::<u32>().unwrap_err();
+        assert_eq!(error.offset, 0);
+    }
+
+    #[test]
+    fn failed_literal_does_not_advance() {
+        let mut cursor = Cursor::new(b"don't()");
+        let error = cursor.expect_literal("do()").unwrap_err();
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(error.offset, 2);
+        assert_eq!(
+            error.error,
+            ParseError::Expected {
+                expected: "do()".to_owned(),
+                found: Some(b'n')
+            }
+        );
+        assert_eq!(cursor.expect_literal("don't()"), Ok(()));
+    }
+
+    #[test]
+    fn error_position() {
+        let mut cursor = Cursor::new(b"Register A: 1\nRegister B; 2");
+        cursor.skip_until(|byte| byte == b'\n');
+        cursor.advance(1);
+        cursor.expect_literal("Register B").unwrap();
+        let error = cursor.expect_byte(b':').unwrap_err();
+        assert_eq!((error.offset, error.line, error.column), (24, 2, 11));
+        assert_eq!(
+            error.to_string(),
+            "expected \":\", found ';' at line 2, column 11 (byte 24)"
+        );
+    }
+
+    #[test]
+    fn scanning_and_backtracking() {
+        let mut cursor = Cursor::new(b"abc123def");
+        assert_eq!(cursor.take_while(|byte| byte.is_ascii_alphabetic()), b"abc");
+        let checkpoint = cursor.save();
+        assert_eq!(cursor.number::<u32>(), Ok(123));
+        cursor.restore(checkpoint);
+        assert_eq!(cursor.peek(), Some(b'1'));
+        assert!(cursor.skip_until(|byte| byte == b'f'));
+        assert_eq!(cursor.remaining(), b"f");
+        assert!(!cursor.skip_until(|byte| byte == b'z'));
+        assert!(cursor.is_at_end());
+    }
+
+    #[test]
+    fn parsable_through_iterator() {
+        use crate::parse::Parsable;
+
+        let mut cursor = Cursor::new(b"p=0,4 v=3,-3");
+        let p: Option<u32> = cursor.bytes().next_number();
+        assert_eq!(p, Some(0));
+        assert_eq!(cursor.position(), 4);
+    }
+}
@@ -1,5 +1,8 @@
+use crate::grid::builder::GridError;
+
 pub trait ToDigit {
     fn to_digit(&self) -> Option<u8>;
+    fn to_digit_radix(&self, radix: Radix) -> Option<u8>;
 }
 
 impl ToDigit for u8 {
@@ -9,6 +12,19 @@ impl ToDigit for u8 {
         }
         None
     }
+
+    fn to_digit_radix(&self, radix: Radix) -> Option<u8> {
+        let digit = match self {
+            b'0'..=b'9' => *self - b'0',
+            b'a'..=b'f' => *self - b'a' + 10,
+            b'A'..=b'F' => *self - b'A' + 10,
+            _ => return None,
+        };
+        if digit < radix.base() {
+            return Some(digit);
+        }
+        None
+    }
 }
 
 pub trait Parsable<T>: Iterator {
@@ -19,6 +35,259 @@ pub trait ParsableStrict<T>: Iterator {
     fn next_number_strict(&mut self) -> (Option<T>, Option<u8>);
 }
 
+pub trait ParsableChecked<T>: Iterator {
+    fn next_number_checked(&mut self) -> Result<Option<T>, ParseError>;
+}
+
+// Like `ParsableStrict`, the byte that ended the number comes back even when it overflowed.
+pub trait ParsableStrictChecked<T>: Iterator {
+    fn next_number_strict_checked(&mut self) -> (Result<Option<T>, ParseError>, Option<u8>);
+}
+
+// Implemented for `Lookbehind` only, so the byte that ended the previous number is still known to
+// the next call, e.g. the `x` in "3x-5".
+pub trait ParsableSigned<T>: Iterator {
+    fn next_number_signed(&mut self, sign: Sign) -> Option<T>;
+}
+
+pub trait ParsableRange<T>: Iterator {
+    fn next_range(&mut self) -> Option<(T, T)>;
+}
+
+// Like `ParsableChecked`, a number too big for the type is an error with all of its digits
+// consumed, so the next call picks up after it.
+pub trait ParsableRadix<T>: Iterator {
+    fn next_number_radix(&mut self, radix: Radix) -> Result<Option<T>, ParseError>;
+}
+
+// Any run of `+` and `-` right before the digits is the sign, if `Sign` accepts the byte in front
+// of the run, so "-+94" is -94. Like `ParsableSigned`, it's implemented for `Lookbehind` only.
+pub trait ParsableSignedRadix<T>: Iterator {
+    fn next_number_signed_radix(
+        &mut self,
+        radix: Radix,
+        sign: Sign,
+    ) -> Result<Option<T>, ParseError>;
+}
+
+// When a `-` right before digits counts as a sign. `Boundary` only accepts it at the start or
+// after whitespace, `=`, `,` or `(`, so "a-5" gives 5 while "x=-5" still gives -5.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Sign {
+    Always,
+    Boundary,
+    Never,
+}
+
+impl Sign {
+    fn allows(&self, previous: Option<u8>) -> bool {
+        match self {
+            Sign::Always => true,
+            Sign::Boundary => match previous {
+                None => true,
+                Some(byte) => {
+                    byte.is_ascii_whitespace() || byte == b'=' || byte == b',' || byte == b'('
+                }
+            },
+            Sign::Never => false,
+        }
+    }
+}
+
+// Remembers the last byte it gave out.
+pub struct Lookbehind<I> {
+    bytes: I,
+    last: Option<u8>,
+}
+
+impl<I: Iterator<Item = u8>> Lookbehind<I> {
+    pub fn new(bytes: I) -> Self {
+        Self { bytes, last: None }
+    }
+}
+
+impl<I: Iterator<Item = u8>> Iterator for Lookbehind<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.bytes.next()?;
+        self.last = Some(byte);
+        Some(byte)
+    }
+}
+
+// Numbers may start with the matching `0b`, `0o` or `0x` prefix and use `_` between digits,
+// e.g. "0b1010_0101" or "#70c710". Hexadecimal digits are accepted in either case. A hexadecimal
+// value that starts with a letter needs the `0x` or `#` in front, unless it's read as
+// `BareHexadecimal`, where any token of hexadecimal digits is a number, like both in "dead beef".
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Radix {
+    Binary,
+    Octal,
+    Decimal,
+    Hexadecimal,
+    BareHexadecimal,
+}
+
+impl Radix {
+    pub fn base(&self) -> u8 {
+        match self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hexadecimal | Radix::BareHexadecimal => 16,
+        }
+    }
+
+    fn prefix(&self) -> Option<u8> {
+        match self {
+            Radix::Binary => Some(b'b'),
+            Radix::Octal => Some(b'o'),
+            Radix::Decimal => None,
+            Radix::Hexadecimal | Radix::BareHexadecimal => Some(b'x'),
+        }
+    }
+
+    // Hexadecimal digits spell words too, like the `D` in "D 5", so a hexadecimal number has to
+    // start with a decimal digit at the start of a token, or with any digit right after a `#`.
+    fn starts(&self, byte: u8, previous: Option<u8>) -> bool {
+        match (self, previous) {
+            (Radix::Hexadecimal, Some(b'#')) => true,
+            (Radix::Hexadecimal, Some(previous)) => {
+                byte.is_ascii_digit() && !previous.is_ascii_alphanumeric()
+            }
+            (Radix::Hexadecimal, None) => byte.is_ascii_digit(),
+            (Radix::BareHexadecimal, Some(previous)) => !previous.is_ascii_alphanumeric(),
+            _ => true,
+        }
+    }
+
+    fn format(&self, value: u128) -> String {
+        match self {
+            Radix::Binary => format!("{:b}", value),
+            Radix::Octal => format!("{:o}", value),
+            Radix::Decimal => value.to_string(),
+            Radix::Hexadecimal | Radix::BareHexadecimal => format!("{:x}", value),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    Overflow(String),
+    Expected { expected: String, found: Option<u8> },
+    TooManyDigits(usize),
+    Unexpected(u8),
+    Grid(GridError),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Overflow(digits) => write!(f, "{} does not fit the target type", digits),
+            ParseError::Expected {
+                expected,
+                found: Some(byte),
+            } => write!(f, "expected {:?}, found {:?}", expected, *byte as char),
+            ParseError::Expected {
+                expected,
+                found: None,
+            } => write!(f, "expected {:?}, found end of input", expected),
+            ParseError::TooManyDigits(max) => write!(f, "number has more than {} digits", max),
+            ParseError::Unexpected(byte) => write!(f, "unexpected {:?}", *byte as char),
+            ParseError::Grid(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PositionedError {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub error: ParseError,
+}
+
+impl PositionedError {
+    // Lines and columns count from 1, the offset is in bytes from the start of the input.
+    pub fn new(input: &[u8], offset: usize, error: ParseError) -> Self {
+        let before = &input[..offset.min(input.len())];
+        let line_start = before
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |newline| newline + 1);
+
+        Self {
+            offset,
+            line: before.iter().filter(|byte| **byte == b'\n').count() + 1,
+            column: offset - line_start + 1,
+            error,
+        }
+    }
+}
+
+impl std::fmt::Display for PositionedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {} (byte {})",
+            self.error, self.line, self.column, self.offset
+        )
+    }
+}
+
+impl std::error::Error for PositionedError {}
+
+// Consumes the rest of an overflowing run of digits so the error carries all of them, and hands
+// back the byte after them.
+fn overflow(mut digits: String, bytes: &mut impl Iterator<Item = u8>) -> (ParseError, Option<u8>) {
+    for byte in bytes {
+        if !byte.is_ascii_digit() {
+            return (ParseError::Overflow(digits), Some(byte));
+        }
+        digits.push(byte as char);
+    }
+    (ParseError::Overflow(digits), None)
+}
+
+// The digits read so far as they were written, sign and leading zeros included.
+fn written(negative: bool, zeros: usize, current: impl std::fmt::Display, digit: u8) -> String {
+    let sign = if negative { "-" } else { "" };
+    format!("{}{}{}{}", sign, "0".repeat(zeros), current, digit)
+}
+
+// As `written`, for a number in any radix, with its prefix but without the `_` separators.
+fn written_radix(
+    negative: bool,
+    prefixed: bool,
+    zeros: usize,
+    radix: Radix,
+    current: u128,
+    digit: u8,
+) -> String {
+    let prefix = match radix.prefix() {
+        Some(prefix) if prefixed => format!("0{}", prefix as char),
+        _ => String::new(),
+    };
+    let sign = if negative { "-" } else { "" };
+    let current = if current == 0 {
+        String::new()
+    } else {
+        radix.format(current)
+    };
+    let digit = char::from_digit(digit as u32, radix.base() as u32).unwrap();
+    format!(
+        "{}{}{}{}{}",
+        sign,
+        prefix,
+        "0".repeat(zeros),
+        current,
+        digit
+    )
+}
+
 macro_rules! parsable_number {
     ($type:ident) => {
         impl<T: Iterator<Item = u8>> Parsable<$type> for T {
@@ -139,75 +408,490 @@ macro_rules! parsable_negative_number_strict {
     };
 }
 
-macro_rules! parsable_float_number {
+macro_rules! parsable_number_checked {
     ($type:ident) => {
-        impl<T: Iterator<Item = u8>> Parsable<$type> for T {
-            fn next_number(&mut self) -> Option<$type> {
-                let mut negative = false;
+        impl<T: Iterator<Item = u8>> ParsableChecked<$type> for T {
+            fn next_number_checked(&mut self) -> Result<Option<$type>, ParseError> {
+                let mut zeros = 0;
                 let mut value: Option<$type> = None;
-                for byte in self {
+                while let Some(byte) = self.next() {
                     if let Some(digit) = byte.to_digit() {
                         if let Some(current) = value {
-                            value = Some(current * 10.0 + digit as $type);
+                            match current
+                                .checked_mul(10)
+                                .and_then(|v| v.checked_add(digit as $type))
+                            {
+                                Some(next) => value = Some(next),
+                                None => {
+                                    let digits = written(false, zeros, current, digit);
+                                    return Err(overflow(digits, self).0);
+                                }
+                            }
                         } else {
                             value = Some(digit as $type);
                         }
-                    } else if let Some(value) = value {
-                        if negative {
-                            return Some(-value);
+                        if value == Some(0) {
+                            zeros += 1;
+                        }
+                    } else if value.is_some() {
+                        return Ok(value);
+                    }
+                }
+
+                Ok(value)
+            }
+        }
+    };
+}
+
+macro_rules! parsable_number_strict_checked {
+    ($type:ident) => {
+        impl<T: Iterator<Item = u8>> ParsableStrictChecked<$type> for T {
+            fn next_number_strict_checked(
+                &mut self,
+            ) -> (Result<Option<$type>, ParseError>, Option<u8>) {
+                let mut zeros = 0;
+                let mut value: Option<$type> = None;
+                while let Some(byte) = self.next() {
+                    if let Some(digit) = byte.to_digit() {
+                        if let Some(current) = value {
+                            match current
+                                .checked_mul(10)
+                                .and_then(|v| v.checked_add(digit as $type))
+                            {
+                                Some(next) => value = Some(next),
+                                None => {
+                                    let digits = written(false, zeros, current, digit);
+                                    let (error, terminator) = overflow(digits, self);
+                                    return (Err(error), terminator);
+                                }
+                            }
+                        } else {
+                            value = Some(digit as $type);
+                        }
+                        if value == Some(0) {
+                            zeros += 1;
                         }
-                        return Some(value);
-                    } else if byte == b'-' && value.is_none() {
-                        negative = true;
                     } else {
-                        negative = false;
+                        return (Ok(value), Some(byte));
                     }
                 }
 
-                if let Some(value) = value {
-                    if negative {
-                        return Some(-value);
+                (Ok(value), None)
+            }
+        }
+    };
+}
+
+macro_rules! parsable_range {
+    ($type:ident) => {
+        impl<T: Iterator<Item = u8>> ParsableRange<$type> for T {
+            fn next_range(&mut self) -> Option<($type, $type)> {
+                loop {
+                    let mut digits = false;
+                    // `None` once the start no longer fits, it can't begin a range then.
+                    let mut start: Option<$type> = Some(0);
+                    let mut terminator = None;
+                    for byte in self.by_ref() {
+                        if let Some(digit) = byte.to_digit() {
+                            digits = true;
+                            start = start
+                                .and_then(|v| v.checked_mul(10))
+                                .and_then(|v| v.checked_add(digit as $type));
+                        } else if digits {
+                            terminator = Some(byte);
+                            break;
+                        }
+                    }
+
+                    terminator?;
+                    if let (Some(start), Some(b'-')) = (start, terminator) {
+                        if let (Ok(Some(end)), _) =
+                            ParsableStrictChecked::<$type>::next_number_strict_checked(self)
+                        {
+                            return Some((start, end));
+                        }
                     }
-                    return Some(value);
                 }
-                None
             }
         }
     };
 }
 
-macro_rules! parsable_float_number_strict {
+macro_rules! parsable_negative_number_signed {
     ($type:ident) => {
-        impl<T: Iterator<Item = u8>> ParsableStrict<$type> for T {
-            fn next_number_strict(&mut self) -> (Option<$type>, Option<u8>) {
+        impl<T: Iterator<Item = u8>> ParsableSigned<$type> for Lookbehind<T> {
+            fn next_number_signed(&mut self, sign: Sign) -> Option<$type> {
                 let mut negative = false;
+                let mut previous = self.last;
                 let mut value: Option<$type> = None;
-                for byte in self {
+                while let Some(byte) = self.next() {
                     if let Some(digit) = byte.to_digit() {
                         if let Some(current) = value {
-                            value = Some(current * 10.0 + digit as $type);
+                            value = Some(current * 10 + digit as $type);
                         } else {
                             value = Some(digit as $type);
                         }
-                    } else if byte == b'-' && value.is_none() {
-                        negative = true;
-                    } else if let Some(value) = value {
-                        if negative {
-                            return (Some(-value), Some(byte));
+                    } else if value.is_some() {
+                        break;
+                    } else {
+                        negative = byte == b'-' && sign.allows(previous);
+                    }
+                    previous = Some(byte);
+                }
+
+                if negative {
+                    return value.map(|value| -value);
+                }
+                value
+            }
+        }
+    };
+}
+
+macro_rules! parsable_number_radix {
+    ($type:ident) => {
+        impl<T: Iterator<Item = u8>> ParsableRadix<$type> for T {
+            fn next_number_radix(&mut self, radix: Radix) -> Result<Option<$type>, ParseError> {
+                let mut previous: Option<u8> = None;
+                let mut started = false;
+                let mut prefixed = false;
+                let mut digits = 0;
+                let mut zeros = 0;
+                let mut value: $type = 0;
+                let mut overflowed: Option<String> = None;
+                for byte in self {
+                    match byte.to_digit_radix(radix) {
+                        Some(digit) if started || radix.starts(byte, previous) => {
+                            started = true;
+                            digits += 1;
+                            if let Some(written) = &mut overflowed {
+                                written.push(byte.to_ascii_lowercase() as char);
+                            } else {
+                                match value
+                                    .checked_mul(radix.base() as $type)
+                                    .and_then(|v| v.checked_add(digit as $type))
+                                {
+                                    Some(0) => zeros += 1,
+                                    Some(next) => value = next,
+                                    None => {
+                                        overflowed = Some(written_radix(
+                                            false,
+                                            prefixed,
+                                            zeros,
+                                            radix,
+                                            value as u128,
+                                            digit,
+                                        ))
+                                    }
+                                }
+                            }
                         }
-                        return (Some(value), Some(byte));
+                        _ if started
+                            && !prefixed
+                            && digits == 1
+                            && zeros == 1
+                            && Some(byte) == radix.prefix() =>
+                        {
+                            (digits, zeros, prefixed) = (0, 0, true);
+                        }
+                        _ if started && byte == b'_' => {}
+                        _ if started => break,
+                        _ => {}
+                    }
+                    previous = Some(byte);
+                }
+
+                match overflowed {
+                    Some(written) => Err(ParseError::Overflow(written)),
+                    None => Ok(started.then_some(value)),
+                }
+            }
+        }
+    };
+}
+
+// Negative values accumulate downwards so that the type's minimum is reachable.
+macro_rules! parsable_negative_number_radix {
+    ($type:ident) => {
+        impl<T: Iterator<Item = u8>> ParsableSignedRadix<$type> for Lookbehind<T> {
+            fn next_number_signed_radix(
+                &mut self,
+                radix: Radix,
+                sign: Sign,
+            ) -> Result<Option<$type>, ParseError> {
+                let mut previous = self.last;
+                let mut signs = false;
+                let mut allowed = false;
+                let mut negative = false;
+                let mut started = false;
+                let mut prefixed = false;
+                let mut digits = 0;
+                let mut zeros = 0;
+                let mut value: $type = 0;
+                let mut overflowed: Option<String> = None;
+                while let Some(byte) = self.next() {
+                    match byte.to_digit_radix(radix) {
+                        Some(digit) if started || radix.starts(byte, previous) => {
+                            if !started {
+                                started = true;
+                                negative &= allowed;
+                            }
+                            digits += 1;
+                            if let Some(written) = &mut overflowed {
+                                written.push(byte.to_ascii_lowercase() as char);
+                            } else {
+                                match value.checked_mul(radix.base() as $type).and_then(|v| {
+                                    if negative {
+                                        v.checked_sub(digit as $type)
+                                    } else {
+                                        v.checked_add(digit as $type)
+                                    }
+                                }) {
+                                    Some(0) => zeros += 1,
+                                    Some(next) => value = next,
+                                    None => {
+                                        overflowed = Some(written_radix(
+                                            negative,
+                                            prefixed,
+                                            zeros,
+                                            radix,
+                                            value.unsigned_abs() as u128,
+                                            digit,
+                                        ))
+                                    }
+                                }
+                            }
+                        }
+                        _ if started
+                            && !prefixed
+                            && digits == 1
+                            && zeros == 1
+                            && Some(byte) == radix.prefix() =>
+                        {
+                            (digits, zeros, prefixed) = (0, 0, true);
+                        }
+                        _ if started && byte == b'_' => {}
+                        _ if started => break,
+                        _ if byte == b'-' || byte == b'+' => {
+                            if !signs {
+                                signs = true;
+                                allowed = sign.allows(previous);
+                                negative = false;
+                            }
+                            negative ^= byte == b'-';
+                        }
+                        _ => {
+                            signs = false;
+                            negative = false;
+                        }
+                    }
+                    previous = Some(byte);
+                }
+
+                match overflowed {
+                    Some(written) => Err(ParseError::Overflow(written)),
+                    None => Ok(started.then_some(value)),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! parsable_negative_number_checked {
+    ($type:ident) => {
+        impl<T: Iterator<Item = u8>> ParsableChecked<$type> for T {
+            fn next_number_checked(&mut self) -> Result<Option<$type>, ParseError> {
+                let mut negative = false;
+                let mut zeros = 0;
+                let mut value: Option<$type> = None;
+                while let Some(byte) = self.next() {
+                    if let Some(digit) = byte.to_digit() {
+                        let current = value.unwrap_or(0);
+                        let next = current.checked_mul(10).and_then(|v| {
+                            if negative {
+                                v.checked_sub(digit as $type)
+                            } else {
+                                v.checked_add(digit as $type)
+                            }
+                        });
+
+                        match next {
+                            Some(0) => {
+                                value = Some(0);
+                                zeros += 1;
+                            }
+                            Some(next) => value = Some(next),
+                            None => {
+                                let digits =
+                                    written(negative, zeros, current.unsigned_abs(), digit);
+                                return Err(overflow(digits, self).0);
+                            }
+                        }
+                    } else if value.is_some() {
+                        return Ok(value);
+                    } else if byte == b'-' {
+                        negative = true;
                     } else {
-                        return (None, Some(byte));
+                        negative = false;
                     }
                 }
 
-                if let Some(value) = value {
-                    if negative {
-                        return (Some(-value), None);
+                Ok(value)
+            }
+        }
+    };
+}
+
+macro_rules! parsable_negative_number_strict_checked {
+    ($type:ident) => {
+        impl<T: Iterator<Item = u8>> ParsableStrictChecked<$type> for T {
+            fn next_number_strict_checked(
+                &mut self,
+            ) -> (Result<Option<$type>, ParseError>, Option<u8>) {
+                let mut negative = false;
+                let mut zeros = 0;
+                let mut value: Option<$type> = None;
+                while let Some(byte) = self.next() {
+                    if let Some(digit) = byte.to_digit() {
+                        let current = value.unwrap_or(0);
+                        let next = current.checked_mul(10).and_then(|v| {
+                            if negative {
+                                v.checked_sub(digit as $type)
+                            } else {
+                                v.checked_add(digit as $type)
+                            }
+                        });
+
+                        match next {
+                            Some(0) => {
+                                value = Some(0);
+                                zeros += 1;
+                            }
+                            Some(next) => value = Some(next),
+                            None => {
+                                let digits =
+                                    written(negative, zeros, current.unsigned_abs(), digit);
+                                let (error, terminator) = overflow(digits, self);
+                                return (Err(error), terminator);
+                            }
+                        }
+                    } else if byte == b'-' {
+                        negative = true;
+                    } else {
+                        return (Ok(value), Some(byte));
                     }
-                    return (Some(value), None);
                 }
-                (None, None)
+
+                (Ok(value), None)
+            }
+        }
+    };
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FloatPart {
+    Integer,
+    Fraction,
+    ExponentStart,
+    ExponentSign,
+    Exponent,
+}
+
+// Gathers `[sign] digits [. digits] [(e|E) [sign] digits]` and hands it to `str::parse` for correct
+// rounding. An unfinished exponent such as the `e` in "2east" is left off the number and comes back
+// as the terminator, though the byte after it has been read by then. Numbers written with more than
+// `FLOAT_LENGTH` bytes are read to the end but give `None`.
+const FLOAT_LENGTH: usize = 32;
+
+fn next_float<F: std::str::FromStr>(
+    bytes: &mut impl Iterator<Item = u8>,
+    strict: bool,
+) -> (Option<F>, Option<u8>) {
+    let mut buffer = [0; FLOAT_LENGTH];
+    let mut length = 0;
+    let mut sign: Option<u8> = None;
+
+    for byte in bytes.by_ref() {
+        if byte.is_ascii_digit() {
+            for byte in sign.into_iter().chain([byte]) {
+                buffer[length] = byte;
+                length += 1;
+            }
+            break;
+        } else if byte == b'-' || byte == b'+' {
+            sign = Some(byte);
+        } else if strict {
+            return (None, Some(byte));
+        } else {
+            sign = None;
+        }
+    }
+
+    if length == 0 {
+        return (None, None);
+    }
+
+    let mut part = FloatPart::Integer;
+    let mut complete = length;
+    let mut exponent = None;
+    let mut terminator = None;
+    for byte in bytes {
+        part = match (part, byte) {
+            (FloatPart::Integer | FloatPart::Fraction | FloatPart::Exponent, b'0'..=b'9') => part,
+            (FloatPart::ExponentStart | FloatPart::ExponentSign, b'0'..=b'9') => {
+                FloatPart::Exponent
+            }
+            (FloatPart::Integer, b'.') => FloatPart::Fraction,
+            (FloatPart::Integer | FloatPart::Fraction, b'e' | b'E') => {
+                exponent = Some(byte);
+                FloatPart::ExponentStart
+            }
+            (FloatPart::ExponentStart, b'-' | b'+') => FloatPart::ExponentSign,
+            _ => {
+                terminator = Some(byte);
+                break;
+            }
+        };
+
+        if length < FLOAT_LENGTH {
+            buffer[length] = byte;
+        }
+        length += 1;
+        if matches!(
+            part,
+            FloatPart::Integer | FloatPart::Fraction | FloatPart::Exponent
+        ) {
+            complete = length;
+        }
+    }
+
+    if matches!(part, FloatPart::ExponentStart | FloatPart::ExponentSign) {
+        terminator = exponent;
+    }
+
+    let value = buffer
+        .get(..complete)
+        .and_then(|number| std::str::from_utf8(number).ok())
+        .and_then(|number| number.parse().ok());
+    (value, terminator)
+}
+
+macro_rules! parsable_float_number {
+    ($type:ident) => {
+        impl<T: Iterator<Item = u8>> Parsable<$type> for T {
+            fn next_number(&mut self) -> Option<$type> {
+                next_float(self, false).0
+            }
+        }
+    };
+}
+
+macro_rules! parsable_float_number_strict {
+    ($type:ident) => {
+        impl<T: Iterator<Item = u8>> ParsableStrict<$type> for T {
+            fn next_number_strict(&mut self) -> (Option<$type>, Option<u8>) {
+                next_float(self, true)
             }
         }
     };
@@ -241,3 +925,279 @@ parsable_negative_number_strict!(i32);
 parsable_negative_number_strict!(i64);
 parsable_negative_number_strict!(i128);
 parsable_negative_number_strict!(isize);
+parsable_range!(u8);
+parsable_range!(u16);
+parsable_range!(u32);
+parsable_range!(u64);
+parsable_range!(u128);
+parsable_range!(usize);
+parsable_negative_number_signed!(i8);
+parsable_negative_number_signed!(i16);
+parsable_negative_number_signed!(i32);
+parsable_negative_number_signed!(i64);
+parsable_negative_number_signed!(i128);
+parsable_negative_number_signed!(isize);
+parsable_number_radix!(u8);
+parsable_number_radix!(u16);
+parsable_number_radix!(u32);
+parsable_number_radix!(u64);
+parsable_number_radix!(u128);
+parsable_number_radix!(usize);
+parsable_negative_number_radix!(i8);
+parsable_negative_number_radix!(i16);
+parsable_negative_number_radix!(i32);
+parsable_negative_number_radix!(i64);
+parsable_negative_number_radix!(i128);
+parsable_negative_number_radix!(isize);
+parsable_number_checked!(u8);
+parsable_number_checked!(u16);
+parsable_number_checked!(u32);
+parsable_number_checked!(u64);
+parsable_number_checked!(u128);
+parsable_number_checked!(usize);
+parsable_number_strict_checked!(u8);
+parsable_number_strict_checked!(u16);
+parsable_number_strict_checked!(u32);
+parsable_number_strict_checked!(u64);
+parsable_number_strict_checked!(u128);
+parsable_number_strict_checked!(usize);
+parsable_negative_number_checked!(i8);
+parsable_negative_number_checked!(i16);
+parsable_negative_number_checked!(i32);
+parsable_negative_number_checked!(i64);
+parsable_negative_number_checked!(i128);
+parsable_negative_number_checked!(isize);
+parsable_negative_number_strict_checked!(i8);
+parsable_negative_number_strict_checked!(i16);
+parsable_negative_number_strict_checked!(i32);
+parsable_negative_number_strict_checked!(i64);
+parsable_negative_number_strict_checked!(i128);
+parsable_negative_number_strict_checked!(isize);
+
+pub mod cursor;
+pub mod normalize;
+pub mod scan;
+pub mod sections;
+pub mod swar;
+pub mod tokens;
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("12 34", Some(12))]
+    #[test_case("abc 255", Some(255))]
+    #[test_case("", None)]
+    fn checked_u8(input: &str, expected: Option<u8>) {
+        assert_eq!(input.bytes().next_number_checked(), Ok(expected));
+    }
+
+    #[test_case("256", "256")]
+    #[test_case("x=1000,2", "1000")]
+    #[test_case("12345678901234567890123", "12345678901234567890123")]
+    #[test_case("0256", "0256")]
+    fn checked_u8_overflow(input: &str, digits: &str) {
+        let result: Result<Option<u8>, ParseError> = input.bytes().next_number_checked();
+        assert_eq!(result, Err(ParseError::Overflow(digits.to_owned())));
+    }
+
+    #[test_case("18446744073709551615", Ok(Some(u64::MAX)))]
+    #[test_case("18446744073709551616", Err(ParseError::Overflow("18446744073709551616".to_owned())))]
+    fn checked_u64(input: &str, expected: Result<Option<u64>, ParseError>) {
+        assert_eq!(input.bytes().next_number_checked(), expected);
+    }
+
+    #[test_case("-128", Ok(Some(i8::MIN)))]
+    #[test_case("127", Ok(Some(i8::MAX)))]
+    #[test_case("p=-3,4", Ok(Some(-3)))]
+    #[test_case("128", Err(ParseError::Overflow("128".to_owned())))]
+    #[test_case("-129", Err(ParseError::Overflow("-129".to_owned())))]
+    #[test_case("x=-00129", Err(ParseError::Overflow("-00129".to_owned())))]
+    fn checked_i8(input: &str, expected: Result<Option<i8>, ParseError>) {
+        assert_eq!(input.bytes().next_number_checked(), expected);
+    }
+
+    #[test]
+    fn checked_continues_after_overflow() {
+        let mut bytes = "300 7".bytes();
+        let first: Result<Option<u8>, ParseError> = bytes.next_number_checked();
+        assert!(first.is_err());
+        assert_eq!(bytes.next_number_checked(), Ok(Some(7u8)));
+    }
+
+    #[test_case("42,", (Ok(Some(42)), Some(b',')))]
+    #[test_case("42", (Ok(Some(42)), None))]
+    #[test_case("999)", (Err(ParseError::Overflow("999".to_owned())), Some(b')')))]
+    #[test_case("00999", (Err(ParseError::Overflow("00999".to_owned())), None))]
+    fn strict_checked_u8(input: &str, expected: (Result<Option<u8>, ParseError>, Option<u8>)) {
+        assert_eq!(input.bytes().next_number_strict_checked(), expected);
+    }
+
+    #[test_case("-5", Sign::Boundary, Some(-5))]
+    #[test_case("x=-5", Sign::Boundary, Some(-5))]
+    #[test_case("1,-5", Sign::Boundary, Some(1))]
+    #[test_case("(-5,3)", Sign::Boundary, Some(-5))]
+    #[test_case("y -5", Sign::Boundary, Some(-5))]
+    #[test_case("a-5", Sign::Boundary, Some(5))]
+    #[test_case("kh-5", Sign::Always, Some(-5))]
+    #[test_case("x=-5", Sign::Never, Some(5))]
+    #[test_case("-", Sign::Always, None)]
+    fn signed(input: &str, sign: Sign, expected: Option<i32>) {
+        assert_eq!(
+            Lookbehind::new(input.bytes()).next_number_signed(sign),
+            expected
+        );
+    }
+
+    #[test_case("p=3-5 v=-2", Sign::Boundary, vec![3, 5, -2])]
+    #[test_case("p=3-5 v=-2", Sign::Never, vec![3, 5, 2])]
+    #[test_case("x-1 y=-1,-7", Sign::Boundary, vec![1, -1, -7])]
+    #[test_case("3x-5", Sign::Boundary, vec![3, 5])]
+    #[test_case("3 -5", Sign::Boundary, vec![3, -5])]
+    fn signed_sequence(input: &str, sign: Sign, expected: Vec<i64>) {
+        let mut bytes = Lookbehind::new(input.bytes());
+        let numbers: Vec<i64> = std::iter::from_fn(|| bytes.next_number_signed(sign)).collect();
+        assert_eq!(numbers, expected);
+    }
+
+    #[test_case("10-20", vec![(10, 20)])]
+    #[test_case("2-4,6-8", vec![(2, 4), (6, 8)])]
+    #[test_case("id 7: 1-3 or 5-7", vec![(1, 3), (5, 7)])]
+    #[test_case("1-x 4-5", vec![(4, 5)])]
+    #[test_case("1 2 3", vec![])]
+    #[test_case("99999999999-1", vec![])]
+    #[test_case("99999999999-1 2-3", vec![(2, 3)])]
+    #[test_case("1-99999999999 4-5", vec![(4, 5)])]
+    fn ranges(input: &str, expected: Vec<(u32, u32)>) {
+        let mut bytes = input.bytes();
+        let ranges: Vec<(u32, u32)> = std::iter::from_fn(|| bytes.next_range()).collect();
+        assert_eq!(ranges, expected);
+    }
+
+    #[test_case("3.75", Some(3.75))]
+    #[test_case("x=-3.75, y=2", Some(-3.75))]
+    #[test_case("+2.5", Some(2.5))]
+    #[test_case("1.5e-3", Some(0.0015))]
+    #[test_case("6.02E23 atoms", Some(6.02e23))]
+    #[test_case("1e+2", Some(100.0))]
+    #[test_case("7", Some(7.0))]
+    #[test_case("2east", Some(2.0))]
+    #[test_case("5.", Some(5.0))]
+    #[test_case("1-2", Some(1.0))]
+    #[test_case("no numbers", None)]
+    fn float(input: &str, expected: Option<f64>) {
+        assert_eq!(input.bytes().next_number(), expected);
+    }
+
+    #[test]
+    fn float_sequence() {
+        let mut bytes = "3.75 -0.5,1e3".bytes();
+        let numbers: Vec<f64> = std::iter::from_fn(|| bytes.next_number()).collect();
+        assert_eq!(numbers, vec![3.75, -0.5, 1000.0]);
+    }
+
+    #[test]
+    fn float_f32() {
+        assert_eq!("0.1".bytes().next_number(), Some(0.1f32));
+    }
+
+    #[test_case("3.75,", (Some(3.75), Some(b',')))]
+    #[test_case("-1.5e-3)", (Some(-0.0015), Some(b')')))]
+    #[test_case("2.5", (Some(2.5), None))]
+    #[test_case("x1", (None, Some(b'x')))]
+    #[test_case("-x", (None, Some(b'x')))]
+    #[test_case("2east", (Some(2.0), Some(b'e')))]
+    #[test_case("4.5E+,", (Some(4.5), Some(b'E')))]
+    #[test_case("0.000000000000000000000000000125", (Some(1.25e-28), None))]
+    #[test_case("0.0000000000000000000000000000125", (None, None))]
+    fn float_strict(input: &str, expected: (Option<f64>, Option<u8>)) {
+        assert_eq!(input.bytes().next_number_strict(), expected);
+    }
+
+    #[test_case("#70c710", Radix::Hexadecimal, Ok(Some(0x70c710)))]
+    #[test_case("0xFF_FF", Radix::Hexadecimal, Ok(Some(0xffff)))]
+    #[test_case("0b1010_0101", Radix::Binary, Ok(Some(0b1010_0101)))]
+    #[test_case("mode 0o755", Radix::Octal, Ok(Some(0o755)))]
+    #[test_case("1_000_000", Radix::Decimal, Ok(Some(1_000_000)))]
+    #[test_case("0 1", Radix::Binary, Ok(Some(0)))]
+    #[test_case("2 3", Radix::Binary, Ok(None))]
+    #[test_case("ffffffff", Radix::Hexadecimal, Ok(None))]
+    #[test_case("0xffffffff", Radix::Hexadecimal, Ok(Some(u32::MAX)))]
+    #[test_case("dead beef", Radix::Hexadecimal, Ok(None))]
+    #[test_case("ffffffff", Radix::BareHexadecimal, Ok(Some(u32::MAX)))]
+    #[test_case("dead beef", Radix::BareHexadecimal, Ok(Some(0xdead)))]
+    #[test_case("fffffffff", Radix::BareHexadecimal, Err(ParseError::Overflow("fffffffff".to_owned())))]
+    #[test_case("0x0_1FFFF_FFFF", Radix::Hexadecimal, Err(ParseError::Overflow("0x01ffffffff".to_owned())))]
+    #[test_case("4294967296", Radix::Decimal, Err(ParseError::Overflow("4294967296".to_owned())))]
+    fn radix_u32(input: &str, radix: Radix, expected: Result<Option<u32>, ParseError>) {
+        assert_eq!(input.bytes().next_number_radix(radix), expected);
+    }
+
+    #[test_case("D 5 (#0dc571)", Radix::Hexadecimal, vec![Ok(5), Ok(0x0dc571)])]
+    #[test_case("x1f 2a,#ff", Radix::Hexadecimal, vec![Ok(0x2a), Ok(0xff)])]
+    #[test_case("99999999999 7", Radix::Hexadecimal, vec![Err(ParseError::Overflow("99999999999".to_owned())), Ok(7)])]
+    #[test_case("dead beef,x1f", Radix::BareHexadecimal, vec![Ok(0xdead), Ok(0xbeef)])]
+    fn radix_hex_sequence(input: &str, radix: Radix, expected: Vec<Result<u32, ParseError>>) {
+        let mut bytes = input.bytes();
+        let numbers: Vec<Result<u32, ParseError>> =
+            std::iter::from_fn(|| bytes.next_number_radix(radix).transpose()).collect();
+        assert_eq!(numbers, expected);
+    }
+
+    #[test_case("X+94", Radix::Decimal, Sign::Boundary, Ok(Some(94)))]
+    #[test_case("Y-94", Radix::Decimal, Sign::Always, Ok(Some(-94)))]
+    #[test_case("Y-94", Radix::Decimal, Sign::Boundary, Ok(Some(94)))]
+    #[test_case("-+94", Radix::Decimal, Sign::Boundary, Ok(Some(-94)))]
+    #[test_case("a -+94", Radix::Decimal, Sign::Boundary, Ok(Some(-94)))]
+    #[test_case("+-0x1f", Radix::Hexadecimal, Sign::Always, Ok(Some(-31)))]
+    #[test_case("x=-0x1f", Radix::Hexadecimal, Sign::Never, Ok(Some(31)))]
+    #[test_case("-0x8000_0000", Radix::Hexadecimal, Sign::Boundary, Ok(Some(i32::MIN)))]
+    #[test_case("0x8000_0000", Radix::Hexadecimal, Sign::Boundary, Err(ParseError::Overflow("0x80000000".to_owned())))]
+    #[test_case("-0x8000_0001", Radix::Hexadecimal, Sign::Boundary, Err(ParseError::Overflow("-0x80000001".to_owned())))]
+    fn radix_signs(
+        input: &str,
+        radix: Radix,
+        sign: Sign,
+        expected: Result<Option<i32>, ParseError>,
+    ) {
+        let mut bytes = Lookbehind::new(input.bytes());
+        assert_eq!(bytes.next_number_signed_radix(radix, sign), expected);
+    }
+
+    #[test]
+    fn radix_signed_sequence() {
+        let mut bytes = Lookbehind::new("3x-5 y=-1_0 -200 7".bytes());
+        let numbers: Vec<Result<i8, ParseError>> = std::iter::from_fn(|| {
+            bytes
+                .next_number_signed_radix(Radix::Decimal, Sign::Boundary)
+                .transpose()
+        })
+        .collect();
+        assert_eq!(
+            numbers,
+            vec![
+                Ok(3),
+                Ok(5),
+                Ok(-10),
+                Err(ParseError::Overflow("-200".to_owned())),
+                Ok(7)
+            ]
+        );
+    }
+
+    #[test]
+    fn radix_sequence() {
+        let mut bytes = "1011 0b11,_1".bytes();
+        let numbers: Vec<u8> =
+            std::iter::from_fn(|| bytes.next_number_radix(Radix::Binary).unwrap()).collect();
+        assert_eq!(numbers, vec![0b1011, 0b11, 1]);
+    }
+
+    #[test_case("-5)", (Ok(Some(-5)), Some(b')')))]
+    #[test_case("-200)", (Err(ParseError::Overflow("-200".to_owned())), Some(b')')))]
+    #[test_case("-0200,", (Err(ParseError::Overflow("-0200".to_owned())), Some(b',')))]
+    fn strict_checked_i8(input: &str, expected: (Result<Option<i8>, ParseError>, Option<u8>)) {
+        assert_eq!(input.bytes().next_number_strict_checked(), expected);
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter},
+};
+
+const BOM: &str = "\u{feff}";
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Normalized {
+    pub bom: bool,
+    pub crlf: usize,
+    pub trailing_whitespace: usize,
+}
+
+impl Normalized {
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Display for Normalized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut changes = Vec::new();
+        if self.bom {
+            changes.push("removed byte order mark".to_owned());
+        }
+        if self.crlf > 0 {
+            changes.push(format!(
+                "converted {} CRLF line ending{}",
+                self.crlf,
+                plural(self.crlf)
+            ));
+        }
+        if self.trailing_whitespace > 0 {
+            changes.push(format!(
+                "trimmed trailing whitespace on {} line{}",
+                self.trailing_whitespace,
+                plural(self.trailing_whitespace)
+            ));
+        }
+
+        if changes.is_empty() {
+            return write!(f, "unchanged");
+        }
+        write!(f, "{}", changes.join(", "))
+    }
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+fn is_blank(byte: u8) -> bool {
+    byte == b' ' || byte == b'\t'
+}
+
+// Strips a byte order mark, turns `\r\n` into `\n` and removes spaces and tabs at the end of
+// lines. Clean input, which is the common case, is returned without copying.
+pub fn normalize(input: &str) -> (Cow<'_, str>, Normalized) {
+    let mut changes = Normalized::default();
+
+    let body = match input.strip_prefix(BOM) {
+        Some(body) => {
+            changes.bom = true;
+            body
+        }
+        None => input,
+    };
+
+    for line in body.split('\n') {
+        let line = match line.strip_suffix('\r') {
+            Some(line) => {
+                changes.crlf += 1;
+                line
+            }
+            None => line,
+        };
+        if line.bytes().last().is_some_and(is_blank) {
+            changes.trailing_whitespace += 1;
+        }
+    }
+
+    if changes.is_clean() {
+        return (Cow::Borrowed(input), changes);
+    }
+
+    let mut output = String::with_capacity(body.len());
+    for (index, line) in body.split('\n').enumerate() {
+        if index > 0 {
+            output.push('\n');
+        }
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        output.push_str(line.trim_end_matches([' ', '\t']));
+    }
+
+    (Cow::Owned(output), changes)
+}
+
+#[cfg(test)]
+mod normalize_tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn clean_input_is_borrowed() {
+        let (output, changes) = normalize("#..\n.#.\n");
+        assert!(matches!(output, Cow::Borrowed("#..\n.#.\n")));
+        assert!(changes.is_clean());
+    }
+
+    #[test_case("\u{feff}#..\n", "#..\n", Normalized { bom: true, crlf: 0, trailing_whitespace: 0 })]
+    #[test_case("#..\r\n.#.\r\n", "#..\n.#.\n", Normalized { bom: false, crlf: 2, trailing_whitespace: 0 })]
+    #[test_case("#.. \n.#.\t\r\n", "#..\n.#.\n", Normalized { bom: false, crlf: 1, trailing_whitespace: 2 })]
+    #[test_case("3   4\n", "3   4\n", Normalized::default())]
+    fn changes(input: &str, expected: &str, report: Normalized) {
+        let (output, changes) = normalize(input);
+        assert_eq!(output, expected);
+        assert_eq!(changes, report);
+    }
+
+    #[test]
+    fn report() {
+        let (_, changes) = normalize("\u{feff}a \r\nb\r\n");
+        assert_eq!(
+            changes.to_string(),
+            "removed byte order mark, converted 2 CRLF line endings, trimmed trailing whitespace on 1 line"
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use crate::points::{ipoint::IPoint, point::Point};
+
+use super::{cursor::Cursor, ParseError, PositionedError};
+
+// A value that can fill a `{}` placeholder. The terminator is the first byte of the literal
+// following the placeholder, which lets words stop before punctuation such as `a: b`.
+pub trait Scan<'a>: Sized {
+    fn scan(cursor: &mut Cursor<'a>, terminator: Option<u8>) -> Result<Self, PositionedError>;
+}
+
+macro_rules! scan_number {
+    ($type:ident) => {
+        impl<'a> Scan<'a> for $type {
+            fn scan(cursor: &mut Cursor<'a>, _: Option<u8>) -> Result<Self, PositionedError> {
+                cursor.signed_number()
+            }
+        }
+    };
+}
+
+scan_number!(u8);
+scan_number!(u16);
+scan_number!(u32);
+scan_number!(u64);
+scan_number!(u128);
+scan_number!(usize);
+scan_number!(i8);
+scan_number!(i16);
+scan_number!(i32);
+scan_number!(i64);
+scan_number!(i128);
+scan_number!(isize);
+
+impl<'a> Scan<'a> for Point {
+    fn scan(cursor: &mut Cursor<'a>, _: Option<u8>) -> Result<Self, PositionedError> {
+        let x = cursor.number()?;
+        cursor.expect_byte(b',')?;
+        let y = cursor.number()?;
+        Ok(Point::new(x, y))
+    }
+}
+
+impl<'a> Scan<'a> for IPoint {
+    fn scan(cursor: &mut Cursor<'a>, _: Option<u8>) -> Result<Self, PositionedError> {
+        let x = cursor.signed_number()?;
+        cursor.expect_byte(b',')?;
+        let y = cursor.signed_number()?;
+        Ok(IPoint::new(x, y))
+    }
+}
+
+impl<'a> Scan<'a> for &'a [u8] {
+    fn scan(cursor: &mut Cursor<'a>, terminator: Option<u8>) -> Result<Self, PositionedError> {
+        let word =
+            cursor.take_while(|byte| !byte.is_ascii_whitespace() && Some(byte) != terminator);
+        if word.is_empty() {
+            return Err(cursor.error(ParseError::Expected {
+                expected: "word".to_owned(),
+                found: cursor.peek(),
+            }));
+        }
+        Ok(word)
+    }
+}
+
+// Words end on ASCII bytes or on the leading byte of a character, so they stay valid UTF-8.
+impl<'a> Scan<'a> for &'a str {
+    fn scan(cursor: &mut Cursor<'a>, terminator: Option<u8>) -> Result<Self, PositionedError> {
+        let word = <&[u8]>::scan(cursor, terminator)?;
+        Ok(std::str::from_utf8(word).unwrap())
+    }
+}
+
+impl<'a> Scan<'a> for String {
+    fn scan(cursor: &mut Cursor<'a>, terminator: Option<u8>) -> Result<Self, PositionedError> {
+        <&str>::scan(cursor, terminator).map(str::to_owned)
+    }
+}
+
+pub struct Scanner<'f, 'a> {
+    format: &'f str,
+    cursor: Cursor<'a>,
+}
+
+impl<'f, 'a> Scanner<'f, 'a> {
+    pub fn new(format: &'f str, input: &'a str) -> Self {
+        Self {
+            format,
+            cursor: Cursor::new(input.as_bytes()),
+        }
+    }
+
+    pub fn placeholder<T: Scan<'a>>(&mut self) -> Result<T, PositionedError> {
+        let (literal, rest) = self
+            .format
+            .split_once("{}")
+            .expect("format has fewer placeholders than values");
+
+        self.cursor.expect_literal(literal)?;
+        self.format = rest;
+        T::scan(&mut self.cursor, rest.bytes().next())
+    }
+
+    pub fn finish(mut self) -> Result<(), PositionedError> {
+        assert!(
+            !self.format.contains("{}"),
+            "format has more placeholders than values"
+        );
+
+        self.cursor.expect_literal(self.format)?;
+        if !self.cursor.is_at_end() {
+            return Err(self.cursor.error(ParseError::Expected {
+                expected: "end of input".to_owned(),
+                found: self.cursor.peek(),
+            }));
+        }
+        Ok(())
+    }
+}
+
+pub trait ScanTuple<'a>: Sized {
+    const PLACEHOLDERS: usize;
+
+    fn scan_tuple(scanner: &mut Scanner<'_, 'a>) -> Result<Self, PositionedError>;
+}
+
+macro_rules! scan_tuple {
+    ($($type:ident),+) => {
+        impl<'a, $($type: Scan<'a>),+> ScanTuple<'a> for ($($type,)+) {
+            const PLACEHOLDERS: usize = [$(stringify!($type)),+].len();
+
+            fn scan_tuple(scanner: &mut Scanner<'_, 'a>) -> Result<Self, PositionedError> {
+                Ok(($(scanner.placeholder::<$type>()?,)+))
+            }
+        }
+    };
+}
+
+scan_tuple!(A);
+scan_tuple!(A, B);
+scan_tuple!(A, B, C);
+scan_tuple!(A, B, C, D);
+scan_tuple!(A, B, C, D, E);
+scan_tuple!(A, B, C, D, E, F);
+scan_tuple!(A, B, C, D, E, F, G);
+scan_tuple!(A, B, C, D, E, F, G, H);
+
+// Literal text has to match exactly and the whole input has to be consumed. A format whose
+// placeholder count doesn't match the tuple is a bug in the caller and panics.
+pub fn scan<'a, T: ScanTuple<'a>>(format: &str, input: &'a str) -> Result<T, PositionedError> {
+    let mut scanner = Scanner::new(format, input);
+    let values = T::scan_tuple(&mut scanner)?;
+    scanner.finish()?;
+    Ok(values)
+}
+
+pub const fn placeholders(format: &str) -> usize {
+    let bytes = format.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+            count += 1;
+            i += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+// What `scan!` expands to. The format is a literal there, so a placeholder count that doesn't match
+// the tuple fails the build instead of panicking.
+pub fn scan_literal<'a, const PLACEHOLDERS: usize, T: ScanTuple<'a>>(
+    format: &str,
+    input: &'a str,
+) -> Result<T, PositionedError> {
+    const {
+        assert!(
+            T::PLACEHOLDERS == PLACEHOLDERS,
+            "format and values have different placeholder counts"
+        )
+    };
+    scan(format, input)
+}
+
+#[macro_export]
+macro_rules! scan {
+    ($format:literal, $input:expr) => {
+        $crate::parse::scan::scan_literal::<{ $crate::parse::scan::placeholders($format) }, _>(
+            $format, $input,
+        )
+    };
+    ($format:literal, $input:expr, $($type:ty),+ $(,)?) => {
+        $crate::parse::scan::scan_literal::<
+            { $crate::parse::scan::placeholders($format) },
+            ($($type,)+),
+        >($format, $input)
+    };
+}
+
+#[cfg(test)]
+mod scan_tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn buttons() {
+        let (x, y): (usize, usize) = scan!("Button A: X+{}, Y+{}", "Button A: X+94, Y+34").unwrap();
+        assert_eq!((x, y), (94, 34));
+    }
+
+    #[test]
+    fn robots() {
+        let (p, v) = scan!("p={} v={}", "p=0,4 v=3,-3", Point, IPoint).unwrap();
+        assert_eq!((p, v), (Point::new(0, 4), IPoint::new(3, -3)));
+    }
+
+    #[test]
+    fn words() {
+        let (a, operator, b, output) = scan!(
+            "{} {} {} -> {}",
+            "x00 AND y01 -> z02",
+            &str,
+            &str,
+            &str,
+            String
+        )
+        .unwrap();
+        assert_eq!(
+            (a, operator, b, output.as_str()),
+            ("x00", "AND", "y01", "z02")
+        );
+    }
+
+    #[test]
+    fn word_before_punctuation() {
+        let (name, value) = scan!("{}: {}", "x00: 1", &str, u8).unwrap();
+        assert_eq!((name, value), ("x00", 1));
+    }
+
+    #[test_case("Button A: X+94, Z+34", 16, ParseError::Expected { expected: ", Y+".to_owned(), found: Some(b'Z') })]
+    #[test_case("Button A: X+94, Y+", 18, ParseError::Expected { expected: "digit".to_owned(), found: None })]
+    #[test_case("Button A: X+94, Y+34 ", 20, ParseError::Expected { expected: "end of input".to_owned(), found: Some(b' ') })]
+    #[test_case("Button A: X+300, Y+34", 12, ParseError::Overflow("300".to_owned()))]
+    fn errors(input: &str, offset: usize, error: ParseError) {
+        let result = scan!("Button A: X+{}, Y+{}", input, u8, u8);
+        assert_eq!(
+            result.map_err(|e| (e.offset, e.error)),
+            Err((offset, error))
+        );
+    }
+
+    #[test_case("", 0)]
+    #[test_case("{}: {}", 2)]
+    #[test_case("{{}} {", 1)]
+    fn placeholder_counts(format: &str, expected: usize) {
+        assert_eq!(placeholders(format), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "more placeholders")]
+    fn too_many_placeholders() {
+        let _ = scan::<(u8,)>("{} {}", "1 2");
+    }
+}
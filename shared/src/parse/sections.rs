@@ -0,0 +1,173 @@
+use std::{iter::from_fn, marker::PhantomData};
+
+use crate::points::{ipoint::IPoint, point::Point};
+
+use super::Parsable;
+
+pub trait FromBytes: Sized {
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+// A single value that `Parsable` can read, so several of them can be combined into tuples.
+pub trait Numeric: Sized {
+    fn next_numeric<I: Iterator<Item = u8>>(bytes: &mut I) -> Option<Self>;
+}
+
+macro_rules! numeric {
+    ($type:ident) => {
+        impl Numeric for $type {
+            fn next_numeric<I: Iterator<Item = u8>>(bytes: &mut I) -> Option<Self> {
+                bytes.next_number()
+            }
+        }
+    };
+}
+
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
+numeric!(u128);
+numeric!(usize);
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(i128);
+numeric!(isize);
+numeric!(Point);
+numeric!(IPoint);
+
+impl<N: Numeric> FromBytes for N {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        N::next_numeric(&mut bytes.iter().copied())
+    }
+}
+
+impl<N: Numeric> FromBytes for Vec<N> {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut bytes = bytes.iter().copied();
+        Some(from_fn(|| N::next_numeric(&mut bytes)).collect())
+    }
+}
+
+macro_rules! from_bytes_tuple {
+    ($($type:ident),+) => {
+        impl<$($type: Numeric),+> FromBytes for ($($type,)+) {
+            fn from_bytes(bytes: &[u8]) -> Option<Self> {
+                let mut bytes = bytes.iter().copied();
+                Some(($($type::next_numeric(&mut bytes)?,)+))
+            }
+        }
+    };
+}
+
+from_bytes_tuple!(A, B);
+from_bytes_tuple!(A, B, C);
+from_bytes_tuple!(A, B, C, D);
+
+pub trait Sectioned {
+    fn sections(&self) -> Sections<'_>;
+    fn lines_of<T: FromBytes>(&self) -> LinesOf<'_, T>;
+}
+
+impl Sectioned for str {
+    fn sections(&self) -> Sections<'_> {
+        Sections { rest: self }
+    }
+
+    fn lines_of<T: FromBytes>(&self) -> LinesOf<'_, T> {
+        LinesOf {
+            lines: self.lines().enumerate(),
+            output: PhantomData,
+        }
+    }
+}
+
+// Blocks of lines separated by one or more blank lines, without their final line ending.
+pub struct Sections<'a> {
+    rest: &'a str,
+}
+
+fn is_blank_start(text: &str) -> bool {
+    text.is_empty() || text.starts_with('\n') || text.starts_with("\r\n")
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rest = self.rest.trim_start_matches(['\r', '\n']);
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let mut end = 0;
+        while let Some(offset) = self.rest[end..].find('\n') {
+            let next = end + offset + 1;
+            if is_blank_start(&self.rest[next..]) {
+                let section = &self.rest[..end + offset];
+                self.rest = &self.rest[next..];
+                return Some(section.strip_suffix('\r').unwrap_or(section));
+            }
+            end = next;
+        }
+
+        let section = self.rest;
+        self.rest = "";
+        Some(section.strip_suffix('\r').unwrap_or(section))
+    }
+}
+
+pub struct LinesOf<'a, T> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    output: PhantomData<T>,
+}
+
+impl<T: FromBytes> Iterator for LinesOf<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, line) = self.lines.next()?;
+        match T::from_bytes(line.as_bytes()) {
+            Some(value) => Some(value),
+            None => panic!("Unable to parse line {}: {:?}", index + 1, line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod sections_tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("a\nb\n\nc\n", vec!["a\nb", "c"])]
+    #[test_case("a\r\nb\r\n\r\nc\r\n", vec!["a\r\nb", "c"])]
+    #[test_case("\n\na\n\n\n\nb", vec!["a", "b"])]
+    #[test_case("a", vec!["a"])]
+    #[test_case("", vec![])]
+    fn sections(input: &str, expected: Vec<&str>) {
+        assert_eq!(input.sections().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn typed_lines() {
+        let mut sections = "47|53\r\n97|13\r\n\r\n75,47,61\r\n".sections();
+        let rules: Vec<(u8, u8)> = sections.next().unwrap().lines_of().collect();
+        let updates: Vec<Vec<u8>> = sections.next().unwrap().lines_of().collect();
+        assert_eq!(rules, vec![(47, 53), (97, 13)]);
+        assert_eq!(updates, vec![vec![75, 47, 61]]);
+    }
+
+    #[test]
+    fn points() {
+        let robots: Vec<(Point, IPoint)> = "p=0,4 v=3,-3".lines_of().collect();
+        assert_eq!(robots, vec![(Point::new(0, 4), IPoint::new(3, -3))]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unable to parse line 2")]
+    fn missing_value() {
+        let _: Vec<(u8, u8)> = "1 2\n3".lines_of().collect();
+    }
+}
@@ -0,0 +1,277 @@
+use std::marker::PhantomData;
+
+const ONES: u64 = 0x0101010101010101;
+const HIGH_BITS: u64 = 0x8080808080808080;
+const POWERS: [u64; 9] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+];
+
+// Unsigned numbers accumulate modulo their size, the same way the release build of `Parsable` does.
+pub trait FromSwar: Copy {
+    fn from_u64(value: u64) -> Self;
+    fn push_digit(self, digit: u8) -> Self;
+}
+
+macro_rules! from_swar {
+    ($type:ident) => {
+        impl FromSwar for $type {
+            fn from_u64(value: u64) -> Self {
+                value as $type
+            }
+
+            fn push_digit(self, digit: u8) -> Self {
+                self.wrapping_mul(10).wrapping_add(digit as $type)
+            }
+        }
+    };
+}
+
+from_swar!(u8);
+from_swar!(u16);
+from_swar!(u32);
+from_swar!(u64);
+from_swar!(u128);
+from_swar!(usize);
+
+// A byte is a digit when it's below 0x80, at least 0x30 and below 0x3A. Adding to the low seven
+// bits can't carry into the next byte, so each check runs on all eight bytes at once.
+fn non_digits(chunk: u64) -> u64 {
+    let low = chunk & !HIGH_BITS;
+    let below_zero = !(low + ONES * (0x80 - b'0' as u64));
+    let above_nine = low + ONES * (0x80 - b':' as u64);
+    (chunk | below_zero | above_nine) & HIGH_BITS
+}
+
+pub fn leading_digits(chunk: u64) -> usize {
+    non_digits(chunk).trailing_zeros() as usize / 8
+}
+
+// Converts the first `length` bytes of a little endian chunk, which all have to be digits.
+pub fn parse_chunk(chunk: u64, length: usize) -> u64 {
+    debug_assert!((1..=8).contains(&length));
+    let digits = (chunk & (ONES * 0x0F)) << ((8 - length) * 8);
+
+    let pairs = digits.wrapping_mul(10).wrapping_add(digits >> 8);
+    let quads = (pairs & 0x000000FF000000FF).wrapping_mul(100 + (1_000_000 << 32));
+    let rest = ((pairs >> 16) & 0x000000FF000000FF).wrapping_mul(1 + (10_000 << 32));
+    quads.wrapping_add(rest) >> 32
+}
+
+pub fn parse_scalar<T: FromSwar>(digits: &[u8]) -> T {
+    digits
+        .iter()
+        .fold(T::from_u64(0), |value, byte| value.push_digit(byte - b'0'))
+}
+
+// Parses a slice made only of digits, `None` when anything else is in it.
+pub fn parse_fixed<T: FromSwar>(digits: &[u8]) -> Option<T> {
+    if digits.is_empty() {
+        return None;
+    }
+    if digits.len() > 16 {
+        return digits
+            .iter()
+            .all(u8::is_ascii_digit)
+            .then(|| parse_scalar(digits));
+    }
+
+    let block = load(digits, 0);
+    if digit_run(&block) < digits.len() {
+        return None;
+    }
+    Some(T::from_u64(parse_block(&block, digits.len())))
+}
+
+fn load(bytes: &[u8], position: usize) -> [u8; 16] {
+    let mut block = [0; 16];
+    let available = &bytes[position..];
+    let length = available.len().min(16);
+    block[..length].copy_from_slice(&available[..length]);
+    block
+}
+
+fn halves(block: &[u8; 16]) -> (u64, u64) {
+    let (low, high) = block.split_at(8);
+    (
+        u64::from_le_bytes(low.try_into().unwrap()),
+        u64::from_le_bytes(high.try_into().unwrap()),
+    )
+}
+
+fn parse_block(block: &[u8; 16], length: usize) -> u64 {
+    let (low, high) = halves(block);
+    if length <= 8 {
+        return parse_chunk(low, length);
+    }
+    parse_chunk(low, 8) * POWERS[length - 8] + parse_chunk(high, length - 8)
+}
+
+#[cfg(target_arch = "x86_64")]
+fn digit_run(block: &[u8; 16]) -> usize {
+    use std::arch::x86_64::{
+        _mm_cmpeq_epi8, _mm_loadu_si128, _mm_min_epu8, _mm_movemask_epi8, _mm_set1_epi8,
+        _mm_sub_epi8,
+    };
+
+    // SAFETY: The intrinsics need SSE2, which every x86_64 target enables, and this function is
+    // only compiled for x86_64, so no runtime detection is needed. The one load reads exactly 16
+    // bytes from `block`, a `[u8; 16]`, and `_mm_loadu_si128` doesn't require any alignment.
+    unsafe {
+        let bytes = _mm_loadu_si128(block.as_ptr().cast());
+        let values = _mm_sub_epi8(bytes, _mm_set1_epi8(b'0' as i8));
+        let digits = _mm_cmpeq_epi8(_mm_min_epu8(values, _mm_set1_epi8(9)), values);
+        (!(_mm_movemask_epi8(digits) as u32)).trailing_zeros() as usize
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn digit_run(block: &[u8; 16]) -> usize {
+    digit_run_swar(block)
+}
+
+#[cfg(any(test, not(target_arch = "x86_64")))]
+fn digit_run_swar(block: &[u8; 16]) -> usize {
+    let (low, high) = halves(block);
+    match leading_digits(low) {
+        8 => 8 + leading_digits(high),
+        run => run,
+    }
+}
+
+// Yields the same unsigned numbers as repeated `Parsable::next_number` calls, but works on a
+// slice so that digits can be found and converted a block at a time.
+pub struct Numbers<'a, T> {
+    bytes: &'a [u8],
+    position: usize,
+    output: PhantomData<T>,
+}
+
+pub fn numbers<T: FromSwar>(bytes: &[u8]) -> Numbers<'_, T> {
+    Numbers {
+        bytes,
+        position: 0,
+        output: PhantomData,
+    }
+}
+
+impl<T: FromSwar> Iterator for Numbers<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.position
+            + self.bytes[self.position..]
+                .iter()
+                .position(u8::is_ascii_digit)?;
+
+        let block = load(self.bytes, start);
+        let mut length = digit_run(&block);
+        if length < 16 {
+            self.position = start + length;
+            return Some(T::from_u64(parse_block(&block, length)));
+        }
+
+        // Longer runs than a block can convert at once fall back to the scalar path.
+        loop {
+            let run = digit_run(&load(self.bytes, start + length));
+            length += run;
+            if run < 16 {
+                break;
+            }
+        }
+
+        self.position = start + length;
+        Some(parse_scalar(&self.bytes[start..self.position]))
+    }
+}
+
+#[cfg(test)]
+mod swar_tests {
+    use std::iter::from_fn;
+
+    use super::*;
+    use crate::parse::Parsable;
+    use test_case::test_case;
+
+    #[test_case(b"12345678", 8, 12345678)]
+    #[test_case(b"00000001", 8, 1)]
+    #[test_case(b"12345   ", 5, 12345)]
+    #[test_case(b"7,8,9,10", 1, 7)]
+    fn chunks(bytes: &[u8; 8], length: usize, expected: u64) {
+        let chunk = u64::from_le_bytes(*bytes);
+        assert_eq!(leading_digits(chunk), length);
+        assert_eq!(parse_chunk(chunk, length), expected);
+    }
+
+    #[test_case(b"", None)]
+    #[test_case(b"90210", Some(90210))]
+    #[test_case(b"1234567890123456", Some(1234567890123456))]
+    #[test_case(b"12345678901234567890", Some(12345678901234567890))]
+    #[test_case(b"12 45", None)]
+    #[test_case(b"12345678901234567x90", None)]
+    fn fixed(digits: &[u8], expected: Option<u64>) {
+        assert_eq!(parse_fixed(digits), expected);
+    }
+
+    #[test]
+    fn lines() {
+        let values: Vec<u32> = numbers(b"38665   13337\n84587   21418\n").collect();
+        assert_eq!(values, vec![38665, 13337, 84587, 21418]);
+    }
+
+    // xorshift keeps the inputs reproducible without pulling in a random number crate.
+    fn random(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn random_input(state: &mut u64, max_digits: u64) -> Vec<u8> {
+        let mut input = Vec::new();
+        for _ in 0..random(state) % 40 {
+            for _ in 0..=random(state) % 2 {
+                input.push(b" ,-\nx:|\xff"[random(state) as usize % 8]);
+            }
+            for _ in 0..=random(state) % max_digits {
+                input.push(b'0' + (random(state) % 10) as u8);
+            }
+        }
+        input
+    }
+
+    #[test]
+    fn digit_runs_agree() {
+        let mut state = 0x1225;
+        for _ in 0..10_000 {
+            let input = random_input(&mut state, 20);
+            for position in 0..input.len() {
+                let block = load(&input, position);
+                assert_eq!(digit_run(&block), digit_run_swar(&block));
+            }
+        }
+    }
+
+    #[test]
+    fn fuzz_against_parsable() {
+        let mut state = 0x2024_1201;
+        for _ in 0..10_000 {
+            let input = random_input(&mut state, 19);
+            let mut bytes = input.iter().copied();
+            let expected: Vec<u64> = from_fn(|| bytes.next_number()).collect();
+            assert_eq!(numbers::<u64>(&input).collect::<Vec<_>>(), expected);
+
+            let input = random_input(&mut state, 38);
+            let mut bytes = input.iter().copied();
+            let expected: Vec<u128> = from_fn(|| bytes.next_number()).collect();
+            assert_eq!(numbers::<u128>(&input).collect::<Vec<_>>(), expected);
+        }
+    }
+}
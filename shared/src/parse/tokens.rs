@@ -0,0 +1,181 @@
+use std::ops::Range;
+
+use crate::misc::translator::Translator;
+
+const OPERATORS: [&str; 8] = ["->", "=>", "<=", ">=", "==", "!=", "&&", "||"];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Kind {
+    Identifier,
+    Number,
+    Operator,
+    Punctuation,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Token<'a> {
+    pub kind: Kind,
+    pub text: &'a str,
+    pub start: usize,
+}
+
+impl<'a> Token<'a> {
+    pub fn bytes(&self) -> &'a [u8] {
+        self.text.as_bytes()
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+
+    pub fn intern(&self, translator: &mut Translator<&'a str>) -> usize {
+        translator.translate(self.text)
+    }
+}
+
+fn is_identifier(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+fn is_operator(byte: u8) -> bool {
+    b"+-*/%=<>!&|^~".contains(&byte)
+}
+
+fn classify(rest: &str) -> (Kind, usize) {
+    let bytes = rest.as_bytes();
+    let first = bytes[0];
+
+    if first.is_ascii_digit() {
+        let length = bytes
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        return (Kind::Number, length);
+    }
+
+    if is_identifier(first) {
+        let length = bytes
+            .iter()
+            .take_while(|byte| is_identifier(**byte))
+            .count();
+        return (Kind::Identifier, length);
+    }
+
+    if let Some(operator) = OPERATORS
+        .iter()
+        .find(|operator| rest.starts_with(*operator))
+    {
+        return (Kind::Operator, operator.len());
+    }
+
+    if is_operator(first) {
+        return (Kind::Operator, 1);
+    }
+
+    let length = rest.chars().next().map_or(1, char::len_utf8);
+    (Kind::Punctuation, length)
+}
+
+// Splits input into identifiers, unsigned numbers, operators and punctuation without copying.
+// A `-` is always an operator, so "kh-tc" gives two identifiers and "-3" a `-` and a number.
+pub struct Tokenizer<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn next_identifier(&mut self) -> Option<Token<'a>> {
+        self.find(|token| token.kind == Kind::Identifier)
+    }
+
+    pub fn next_interned(&mut self, translator: &mut Translator<&'a str>) -> Option<usize> {
+        self.next_identifier().map(|token| token.intern(translator))
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.position..];
+        let start = self.position + (rest.len() - rest.trim_start().len());
+        let rest = &self.input[start..];
+        if rest.is_empty() {
+            self.position = start;
+            return None;
+        }
+
+        let (kind, length) = classify(rest);
+        self.position = start + length;
+
+        Some(Token {
+            kind,
+            text: &rest[..length],
+            start,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tokens_tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn kinds(input: &str) -> Vec<(Kind, &str)> {
+        Tokenizer::new(input)
+            .map(|token| (token.kind, token.text))
+            .collect()
+    }
+
+    #[test]
+    fn gates() {
+        assert_eq!(
+            kinds("x00 AND y01 -> z02"),
+            vec![
+                (Kind::Identifier, "x00"),
+                (Kind::Identifier, "AND"),
+                (Kind::Identifier, "y01"),
+                (Kind::Operator, "->"),
+                (Kind::Identifier, "z02"),
+            ]
+        );
+    }
+
+    #[test_case("kh-tc", vec![(Kind::Identifier, "kh"), (Kind::Operator, "-"), (Kind::Identifier, "tc")])]
+    #[test_case("x=-3", vec![(Kind::Identifier, "x"), (Kind::Operator, "="), (Kind::Operator, "-"), (Kind::Number, "3")])]
+    #[test_case("a: b, 42;", vec![(Kind::Identifier, "a"), (Kind::Punctuation, ":"), (Kind::Identifier, "b"), (Kind::Punctuation, ","), (Kind::Number, "42"), (Kind::Punctuation, ";")])]
+    #[test_case("  ", vec![])]
+    fn kinds_of(input: &str, expected: Vec<(Kind, &str)>) {
+        assert_eq!(kinds(input), expected);
+    }
+
+    #[test]
+    fn spans() {
+        let tokens: Vec<Token> = Tokenizer::new("ab  -> é").collect();
+        assert_eq!(tokens[1].span(), 4..6);
+        assert_eq!(tokens[2].span(), 7..9);
+        assert_eq!(tokens[2].kind, Kind::Punctuation);
+        assert_eq!(tokens[0].bytes(), b"ab");
+    }
+
+    #[test]
+    fn interning() {
+        let mut translator = Translator::new();
+        let mut ids = Vec::new();
+        for line in "kh-tc\nqp-kh\nde-cg\nka-co\nyn-aq\nqp-ub".lines() {
+            let mut tokens = Tokenizer::new(line);
+            while let Some(id) = tokens.next_interned(&mut translator) {
+                ids.push(id);
+            }
+        }
+        assert_eq!(ids, vec![0, 1, 2, 0, 3, 4, 5, 6, 7, 8, 2, 9]);
+    }
+}
@@ -18,6 +18,13 @@ pub const DIRECTIONS: [Direction; 8] = [
     Direction::NorthWest,
 ];
 
+pub const ORDINALS: [Direction; 4] = [
+    Direction::NorthEast,
+    Direction::SouthEast,
+    Direction::SouthWest,
+    Direction::NorthWest,
+];
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Direction {
     North = 0,
@@ -7,6 +7,7 @@ use std::{
 use forward_ref::{forward_ref_binop, forward_ref_op_assign};
 
 use super::{
+    directions::Direction,
     point::Point,
     traits::{Absolute, Modulo, ModuloAssign, ModuloPositive, ModuloPositiveAssign},
 };
@@ -32,6 +33,21 @@ impl From<Point> for IPoint {
     }
 }
 
+impl From<Direction> for IPoint {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => IPoint::new(0, -1),
+            Direction::NorthEast => IPoint::new(1, -1),
+            Direction::East => IPoint::new(1, 0),
+            Direction::SouthEast => IPoint::new(1, 1),
+            Direction::South => IPoint::new(0, 1),
+            Direction::SouthWest => IPoint::new(-1, 1),
+            Direction::West => IPoint::new(-1, 0),
+            Direction::NorthWest => IPoint::new(-1, -1),
+        }
+    }
+}
+
 impl IPoint {
     pub fn new(x: isize, y: isize) -> Self {
         IPoint { x, y }
@@ -57,6 +73,10 @@ impl IPoint {
         None
     }
 
+    pub fn go(&self, direction: Direction) -> Self {
+        *self + IPoint::from(direction)
+    }
+
     pub fn distance_to(&self, rhs: Self) -> usize {
         self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y)
     }
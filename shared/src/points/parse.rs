@@ -1,6 +1,6 @@
 use crate::parse::Parsable;
 
-use super::{ipoint::IPoint, point::Point};
+use super::{ipoint::IPoint, point::Point, vec3::Vec3};
 
 impl<T: Iterator<Item = u8>> Parsable<Point> for T {
     fn next_number(&mut self) -> Option<Point> {
@@ -19,3 +19,13 @@ impl<T: Iterator<Item = u8>> Parsable<IPoint> for T {
         None
     }
 }
+
+impl<T: Iterator<Item = u8>> Parsable<Vec3> for T {
+    fn next_number(&mut self) -> Option<Vec3> {
+        Some(Vec3::new(
+            self.next_number()?,
+            self.next_number()?,
+            self.next_number()?,
+        ))
+    }
+}
@@ -0,0 +1,198 @@
+use std::mem::{replace, swap};
+
+const WORD: usize = u64::BITS as usize;
+
+// A priority queue for keys that never drop below the last one popped, which is what Dijkstra
+// and A* produce. It keeps one bucket per key in a ring that covers every queued key, so pushing
+// and popping only index into it, and a bitmap of the buckets in use lets a pop skip over empty
+// stretches a word at a time. The ring grows to fit whenever a key lands beyond it, which suits
+// the small integer step costs of puzzle searches, but not costs in the millions.
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    occupied: Vec<u64>,
+    // The bucket being drained, taken out of the ring so that popping from it stays cheap.
+    active: Vec<T>,
+    current: usize,
+    slot: usize,
+    len: usize,
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BucketQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            buckets: (0..WORD).map(|_| Vec::new()).collect(),
+            occupied: vec![0; 1],
+            active: Vec::new(),
+            current: 0,
+            slot: 0,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, key: usize, value: T) {
+        debug_assert!(
+            key >= self.current,
+            "{} is below the last key {}",
+            key,
+            self.current
+        );
+        self.len += 1;
+        let distance = key - self.current;
+        if distance == 0 {
+            self.active.push(value);
+            return;
+        }
+
+        if distance >= self.buckets.len() {
+            self.grow(distance + 1);
+        }
+
+        // The ring isn't a power of two long, so wrapping is a subtraction rather than a mask.
+        let mut slot = self.slot + distance;
+        if slot >= self.buckets.len() {
+            slot -= self.buckets.len();
+        }
+        self.buckets[slot].push(value);
+        self.occupied[slot / WORD] |= 1 << (slot % WORD);
+    }
+
+    // Values with the same key come out last in, first out.
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        if let Some(value) = self.active.pop() {
+            self.len -= 1;
+            return Some((self.current, value));
+        }
+        if self.len == 0 {
+            return None;
+        }
+
+        let size = self.buckets.len();
+        let start = if self.slot + 1 == size {
+            0
+        } else {
+            self.slot + 1
+        };
+        let slot = self.next_occupied(start);
+        self.current += if slot >= start {
+            slot - start + 1
+        } else {
+            slot + size - start + 1
+        };
+        self.slot = slot;
+        self.occupied[slot / WORD] &= !(1 << (slot % WORD));
+        swap(&mut self.buckets[slot], &mut self.active);
+
+        self.len -= 1;
+        self.active.pop().map(|value| (self.current, value))
+    }
+
+    // Only called with something queued outside the active bucket, so the search always ends.
+    // Bits past the end of the ring are never set, so the last word needs no special casing.
+    fn next_occupied(&self, start: usize) -> usize {
+        let mut word = start / WORD;
+        let mut bits = self.occupied[word] & (u64::MAX << (start % WORD));
+        while bits == 0 {
+            word += 1;
+            if word == self.occupied.len() {
+                word = 0;
+            }
+            bits = self.occupied[word];
+        }
+        word * WORD + bits.trailing_zeros() as usize
+    }
+
+    // Buckets are laid out again by how far their key is past the current one.
+    #[cold]
+    fn grow(&mut self, span: usize) {
+        let size = span.max(self.buckets.len() * 2);
+        let old = replace(&mut self.buckets, (0..size).map(|_| Vec::new()).collect());
+        self.occupied = vec![0; size.div_ceil(WORD)];
+
+        let length = old.len();
+        for (slot, bucket) in old.into_iter().enumerate() {
+            if bucket.is_empty() {
+                continue;
+            }
+            let distance = (slot + length - self.slot) % length;
+            self.buckets[distance] = bucket;
+            self.occupied[distance / WORD] |= 1 << (distance % WORD);
+        }
+        self.slot = 0;
+    }
+}
+
+#[cfg(test)]
+mod buckets_tests {
+    use super::*;
+
+    fn drain(queue: &mut BucketQueue<usize>) -> Vec<usize> {
+        std::iter::from_fn(|| queue.pop().map(|(key, _)| key)).collect()
+    }
+
+    #[test]
+    fn pops_in_order() {
+        let mut queue = BucketQueue::new();
+        for key in [5, 1, 8, 1, 3] {
+            queue.push(key, key);
+        }
+        assert_eq!(queue.pop(), Some((1, 1)));
+        queue.push(1, 1);
+        queue.push(2, 2);
+        assert_eq!(drain(&mut queue), vec![1, 1, 2, 3, 5, 8]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn grows_past_the_ring() {
+        let mut queue = BucketQueue::new();
+        queue.push(3, 3);
+        queue.push(60, 60);
+        assert_eq!(queue.pop(), Some((3, 3)));
+        queue.push(1000, 1000);
+        queue.push(64, 64);
+        queue.push(3000, 3000);
+        assert_eq!(drain(&mut queue), vec![60, 64, 1000, 3000]);
+    }
+
+    #[test]
+    fn monotone_against_sort() {
+        let mut state: u64 = 0x1216;
+        let mut queue = BucketQueue::new();
+        let mut popped = Vec::new();
+        let mut pushed = Vec::new();
+        for _ in 0..10_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state.is_multiple_of(3) {
+                if let Some((key, value)) = queue.pop() {
+                    assert_eq!(key, value);
+                    popped.push(key);
+                }
+            } else {
+                let last = popped.last().copied().unwrap_or(0);
+                let key = last + (state % 2001) as usize;
+                queue.push(key, key);
+                pushed.push(key);
+            }
+        }
+        popped.extend(drain(&mut queue));
+        assert!(popped.windows(2).all(|pair| pair[0] <= pair[1]));
+        pushed.sort_unstable();
+        assert_eq!(popped, pushed);
+    }
+}
@@ -0,0 +1,129 @@
+use std::hash::Hash;
+
+use crate::{
+    grid::Grid,
+    misc::translator::Translator,
+    points::{
+        directions::{Direction, CARDINALS},
+        point::Point,
+    },
+};
+
+// Maps search states onto slots of the flat cost and predecessor tables and back. `index` may
+// hand out a new slot, `find` only looks one up.
+pub trait Indexer<S> {
+    fn index(&mut self, state: &S) -> usize;
+    fn find(&self, state: &S) -> Option<usize>;
+    fn state(&self, index: usize) -> S;
+
+    fn size_hint(&self) -> usize {
+        0
+    }
+}
+
+// States that can be numbered up front, like the cells of a grid.
+pub struct Dense<F, G> {
+    size: usize,
+    index: F,
+    state: G,
+}
+
+impl<F, G> Dense<F, G> {
+    pub fn new(size: usize, index: F, state: G) -> Self {
+        Self { size, index, state }
+    }
+}
+
+impl<S, F, G> Indexer<S> for Dense<F, G>
+where
+    F: Fn(&S) -> usize,
+    G: Fn(usize) -> S,
+{
+    fn index(&mut self, state: &S) -> usize {
+        (self.index)(state)
+    }
+
+    fn find(&self, state: &S) -> Option<usize> {
+        Some((self.index)(state))
+    }
+
+    fn state(&self, index: usize) -> S {
+        (self.state)(index)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.size
+    }
+}
+
+// Any hashable state, numbered in the order the search first reaches it.
+pub struct Hashed<S>
+where
+    S: Hash + Eq,
+{
+    translator: Translator<S>,
+    states: Vec<S>,
+}
+
+impl<S> Default for Hashed<S>
+where
+    S: Hash + Eq,
+{
+    fn default() -> Self {
+        Self {
+            translator: Translator::new(),
+            states: Vec::new(),
+        }
+    }
+}
+
+impl<S> Indexer<S> for Hashed<S>
+where
+    S: Hash + Eq + Copy,
+{
+    fn index(&mut self, state: &S) -> usize {
+        let index = self.translator.translate(*state);
+        if index == self.states.len() {
+            self.states.push(*state);
+        }
+        index
+    }
+
+    fn find(&self, state: &S) -> Option<usize> {
+        self.translator.map.get(state).copied()
+    }
+
+    fn state(&self, index: usize) -> S {
+        self.states[index]
+    }
+}
+
+// A position together with the way it is facing, the usual state when turning costs extra.
+pub type Heading = (Point, Direction);
+
+impl<T> Grid<T> {
+    pub fn point_indexer(&self) -> Dense<impl Fn(&Point) -> usize, impl Fn(usize) -> Point> {
+        let width = self.width;
+        Dense::new(
+            self.vec.len(),
+            move |point: &Point| point.y * width + point.x,
+            move |index| Point::new(index % width, index / width),
+        )
+    }
+
+    // Only the four cardinal directions get a slot, they are the first four variants.
+    pub fn directed_indexer(&self) -> Dense<impl Fn(&Heading) -> usize, impl Fn(usize) -> Heading> {
+        let width = self.width;
+        Dense::new(
+            self.vec.len() * 4,
+            move |(point, direction): &Heading| {
+                debug_assert!((*direction as usize) < 4, "{:?} is not cardinal", direction);
+                (point.y * width + point.x) * 4 + *direction as usize
+            },
+            move |index| {
+                let cell = index / 4;
+                (Point::new(cell % width, cell / width), CARDINALS[index % 4])
+            },
+        )
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::VecDeque;
+
+use buckets::BucketQueue;
+use indexer::Indexer;
+
+// What a search found: the lowest cost to every state it settled or reached, how it got there,
+// and which state satisfied the goal, if one did before the search ran out. Predecessors are
+// kept as slots plus one, so that zero can stand for none and a fresh table costs nothing, in 32
+// bits since writing that table is a good part of what a search over a grid costs.
+pub struct Search<S, I> {
+    indexer: I,
+    costs: Vec<usize>,
+    predecessors: Vec<u32>,
+    goal: Option<S>,
+}
+
+impl<S, I> Search<S, I>
+where
+    S: Copy,
+    I: Indexer<S>,
+{
+    fn new(indexer: I) -> Self {
+        let size = indexer.size_hint();
+        Self {
+            indexer,
+            costs: vec![usize::MAX; size],
+            predecessors: vec![0; size],
+            goal: None,
+        }
+    }
+
+    // Records `cost` for `state` if it beats the best one so far, handing back its slot if so.
+    fn relax(&mut self, state: S, cost: usize, from: Option<usize>) -> Option<usize> {
+        let index = self.indexer.index(&state);
+        match self.costs.get(index) {
+            Some(&best) if cost >= best => return None,
+            Some(_) => {}
+            None => self.grow(index + 1),
+        }
+
+        debug_assert!(
+            index < u32::MAX as usize,
+            "More states than predecessors can hold"
+        );
+        self.costs[index] = cost;
+        self.predecessors[index] = from.map_or(0, |from| from as u32 + 1);
+        Some(index)
+    }
+
+    #[cold]
+    fn grow(&mut self, size: usize) {
+        self.costs.resize(size, usize::MAX);
+        self.predecessors.resize(size, 0);
+    }
+
+    pub fn goal(&self) -> Option<S> {
+        self.goal
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goal.and_then(|goal| self.cost(&goal))
+    }
+
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        let index = self.indexer.find(state)?;
+        self.costs
+            .get(index)
+            .copied()
+            .filter(|cost| *cost != usize::MAX)
+    }
+
+    pub fn predecessor(&self, state: &S) -> Option<S> {
+        let index = self.indexer.find(state)?;
+        match self.predecessors.get(index) {
+            None | Some(0) => None,
+            Some(from) => Some(self.indexer.state(*from as usize - 1)),
+        }
+    }
+
+    // The states from a start up to and including `state`, `None` when it was never reached.
+    pub fn path(&self, state: S) -> Option<Vec<S>> {
+        self.cost(&state)?;
+        let mut path = vec![state];
+        while let Some(previous) = self.predecessor(path.last().unwrap()) {
+            path.push(previous);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// Every step costs one, so a queue visits states in order of cost.
+pub fn bfs<S, I, N>(
+    starts: impl IntoIterator<Item = S>,
+    indexer: I,
+    mut neighbours: impl FnMut(&S) -> N,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S, I>
+where
+    S: Copy,
+    I: Indexer<S>,
+    N: IntoIterator,
+    N::Item: Into<Option<S>>,
+{
+    let mut search = Search::new(indexer);
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.relax(start, 0, None).is_some() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, cost)) = queue.pop_front() {
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let index = search.indexer.find(&state);
+        for next in neighbours(&state) {
+            let Some(next) = next.into() else {
+                continue;
+            };
+            if search.relax(next, cost + 1, index).is_some() {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    search
+}
+
+pub fn dijkstra<S, I, N>(
+    starts: impl IntoIterator<Item = S>,
+    indexer: I,
+    neighbours: impl FnMut(&S) -> N,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S, I>
+where
+    S: Copy,
+    I: Indexer<S>,
+    N: IntoIterator,
+    N::Item: Into<Option<(S, usize)>>,
+{
+    a_star(starts, indexer, neighbours, |_| 0, goal)
+}
+
+// The heuristic must never overestimate the remaining cost for the result to be optimal.
+pub fn a_star<S, I, N>(
+    starts: impl IntoIterator<Item = S>,
+    indexer: I,
+    mut neighbours: impl FnMut(&S) -> N,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S, I>
+where
+    S: Copy,
+    I: Indexer<S>,
+    N: IntoIterator,
+    N::Item: Into<Option<(S, usize)>>,
+{
+    let mut search = Search::new(indexer);
+    let mut queue = BucketQueue::new();
+    for start in starts {
+        if search.relax(start, 0, None).is_some() {
+            queue.push(heuristic(&start), (start, 0));
+        }
+    }
+
+    while let Some((estimate, (state, cost))) = queue.pop() {
+        // A state is queued again every time its cost improves, only the cheapest entry counts.
+        let index = search.indexer.find(&state);
+        if index.is_none_or(|index| cost > search.costs[index]) {
+            continue;
+        }
+
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for next in neighbours(&state) {
+            let Some((next, step)) = next.into() else {
+                continue;
+            };
+            let next_cost = cost + step;
+            if search.relax(next, next_cost, index).is_some() {
+                // Never estimating below the parent keeps the queue monotone even when the
+                // heuristic isn't consistent.
+                let next_estimate = (next_cost + heuristic(&next)).max(estimate);
+                queue.push(next_estimate, (next, next_cost));
+            }
+        }
+    }
+
+    search
+}
+
+pub mod buckets;
+pub mod indexer;
+pub mod paths;
+
+#[cfg(test)]
+mod search_tests {
+    use super::{indexer::Hashed, *};
+    use crate::{
+        grid::Grid,
+        points::{directions::Direction, point::Point},
+    };
+
+    fn maze() -> Grid<bool> {
+        Grid::parse(
+            "#######\n#.....#\n#.###.#\n#...#.#\n###.#.#\n#.....#\n#######\n",
+            |byte, _| Some(byte == b'.'),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn bfs_through_maze() {
+        let maze = maze();
+        let end = Point::new(1, 5);
+        let search = bfs(
+            [Point::new(1, 1)],
+            maze.point_indexer(),
+            |&point| maze.adjacent_four(point).filter(|next| maze[*next]),
+            |&point| point == end,
+        );
+        assert_eq!(search.goal_cost(), Some(8));
+        let path = search.path(end).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!(path[0], Point::new(1, 1));
+        assert!(path
+            .windows(2)
+            .all(|pair| pair[0].distance_to(pair[1]) == 1));
+    }
+
+    #[test]
+    fn dijkstra_with_turns() {
+        let maze = maze();
+        let end = Point::new(5, 5);
+        let search = dijkstra(
+            [(Point::new(1, 1), Direction::East)],
+            maze.directed_indexer(),
+            |&(point, direction)| {
+                [
+                    Some(point.go(direction))
+                        .filter(|next| maze[*next])
+                        .map(|next| ((next, direction), 1)),
+                    Some(((point, direction.rotate_90()), 1000)),
+                    Some(((point, direction.rotate_counter_90()), 1000)),
+                ]
+                .into_iter()
+                .flatten()
+            },
+            |&(point, _)| point == end,
+        );
+        assert_eq!(search.goal(), Some((end, Direction::South)));
+        assert_eq!(search.goal_cost(), Some(1008));
+    }
+
+    #[test]
+    fn a_star_matches_dijkstra() {
+        let maze = maze();
+        let end = Point::new(5, 5);
+        let neighbours = |point: &Point| {
+            maze.adjacent_four(*point)
+                .filter(|next| maze[*next])
+                .map(|next| (next, 1))
+        };
+        let plain = dijkstra(
+            [Point::new(1, 1)],
+            maze.point_indexer(),
+            neighbours,
+            |&point| point == end,
+        );
+        let guided = a_star(
+            [Point::new(1, 1)],
+            maze.point_indexer(),
+            neighbours,
+            |point| point.distance_to(end),
+            |&point| point == end,
+        );
+        assert_eq!(plain.goal_cost(), Some(8));
+        assert_eq!(guided.goal_cost(), plain.goal_cost());
+    }
+
+    #[test]
+    fn hashed_states() {
+        let edges = [("a", "b", 7), ("a", "c", 2), ("c", "b", 3), ("b", "d", 1)];
+        let search = dijkstra(
+            ["a"],
+            Hashed::default(),
+            |&from| {
+                edges
+                    .iter()
+                    .filter(move |edge| edge.0 == from)
+                    .map(|edge| (edge.1, edge.2))
+            },
+            |_| false,
+        );
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.cost(&"d"), Some(6));
+        assert_eq!(search.path("d"), Some(vec!["a", "c", "b", "d"]));
+        assert_eq!(search.cost(&"e"), None);
+    }
+
+    #[test]
+    fn several_starts() {
+        let search = bfs(
+            [0, 10],
+            Hashed::default(),
+            |&n: &i32| [n - 1, n + 1],
+            |&n| n == 4,
+        );
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.cost(&9), Some(1));
+    }
+}
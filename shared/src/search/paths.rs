@@ -0,0 +1,444 @@
+use std::hash::Hash;
+
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::{dijkstra, indexer::Indexer, Search};
+
+// Every optimal path into a set of targets, recovered from the costs a search left behind. The
+// nodes are the states on at least one of those paths, each with every predecessor that reaches
+// it at its best cost. Steps have to cost at least one, or the paths could go round in circles.
+pub struct Dag<'a, S, I> {
+    search: &'a Search<S, I>,
+    // Search slots by increasing cost, so every node comes after all of its predecessors.
+    slots: Vec<usize>,
+    nodes: HashMap<usize, usize>,
+    // The predecessors of node `n` are `edges[offsets[n]..offsets[n + 1]]`.
+    offsets: Vec<usize>,
+    edges: Vec<usize>,
+    ends: Vec<usize>,
+}
+
+impl<S, I> Search<S, I>
+where
+    S: Copy,
+    I: Indexer<S>,
+{
+    // `previous` undoes the search's neighbours: every state with a step into the given one, and
+    // what that step costs. Only the states on an optimal path are looked at, and only costs below
+    // the dearest target are relied on, those are final even when the search stopped at its goal.
+    pub fn dag<N>(
+        &self,
+        targets: impl IntoIterator<Item = S>,
+        mut previous: impl FnMut(&S) -> N,
+    ) -> Dag<'_, S, I>
+    where
+        N: IntoIterator,
+        N::Item: Into<Option<(S, usize)>>,
+    {
+        let mut slots: Vec<usize> = Vec::new();
+        let mut nodes: HashMap<usize, usize> = HashMap::default();
+        for target in targets {
+            let Some(slot) = self.indexer.find(&target) else {
+                continue;
+            };
+            if self.costs.get(slot).is_some_and(|cost| *cost != usize::MAX) {
+                nodes.entry(slot).or_insert_with(|| {
+                    slots.push(slot);
+                    slots.len() - 1
+                });
+            }
+        }
+        let ends: Vec<usize> = (0..slots.len()).collect();
+
+        // Walking back from the targets, a step is optimal when it accounts for the whole
+        // difference in cost, which keeps only the states that lead to one of them. Each node is
+        // walked from once, so its edges come out together.
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut stack = ends.clone();
+        while let Some(node) = stack.pop() {
+            let slot = slots[node];
+            let cost = self.costs[slot];
+            let first = edges.len();
+            for before in previous(&self.indexer.state(slot)) {
+                let Some((before, step)) = before.into() else {
+                    continue;
+                };
+                let Some(from) = self.indexer.find(&before) else {
+                    continue;
+                };
+                if self.costs.get(from).map(|from| from.checked_add(step)) != Some(Some(cost)) {
+                    continue;
+                }
+
+                let from = *nodes.entry(from).or_insert_with(|| {
+                    slots.push(from);
+                    stack.push(slots.len() - 1);
+                    slots.len() - 1
+                });
+                if !edges[first..].contains(&(node, from)) {
+                    edges.push((node, from));
+                }
+            }
+        }
+
+        // Renumbered by increasing cost, so every node comes after all of its predecessors.
+        let mut order: Vec<usize> = (0..slots.len()).collect();
+        order.sort_unstable_by_key(|node| (self.costs[slots[*node]], slots[*node]));
+        let mut renumbered = vec![0; order.len()];
+        for (new, old) in order.iter().enumerate() {
+            renumbered[*old] = new;
+        }
+        for node in nodes.values_mut() {
+            *node = renumbered[*node];
+        }
+
+        let mut offsets = vec![0; order.len() + 1];
+        for (node, _) in &edges {
+            offsets[renumbered[*node] + 1] += 1;
+        }
+        for node in 0..order.len() {
+            offsets[node + 1] += offsets[node];
+        }
+        let mut filled = offsets.clone();
+        let mut predecessors = vec![0; edges.len()];
+        for (node, from) in edges {
+            let node = renumbered[node];
+            predecessors[filled[node]] = renumbered[from];
+            filled[node] += 1;
+        }
+
+        Dag {
+            search: self,
+            slots: order.iter().map(|old| slots[*old]).collect(),
+            nodes,
+            offsets,
+            edges: predecessors,
+            ends: ends.iter().map(|node| renumbered[*node]).collect(),
+        }
+    }
+}
+
+impl<'a, S, I> Dag<'a, S, I>
+where
+    S: Copy,
+    I: Indexer<S>,
+{
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    fn incoming(&self, node: usize) -> &[usize] {
+        &self.edges[self.offsets[node]..self.offsets[node + 1]]
+    }
+
+    fn state(&self, node: usize) -> S {
+        self.search.indexer.state(self.slots[node])
+    }
+
+    fn node(&self, state: &S) -> Option<usize> {
+        let slot = self.search.indexer.find(state)?;
+        self.nodes.get(&slot).copied()
+    }
+
+    // Every state on some optimal path, cheapest first.
+    pub fn states(&self) -> impl Iterator<Item = S> + '_ {
+        (0..self.len()).map(|node| self.state(node))
+    }
+
+    pub fn contains(&self, state: &S) -> bool {
+        self.node(state).is_some()
+    }
+
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = S> + '_ {
+        self.node(state)
+            .into_iter()
+            .flat_map(|node| self.incoming(node))
+            .map(|node| self.state(*node))
+    }
+
+    // How many distinct optimal paths there are, `None` when that doesn't fit in a usize.
+    pub fn path_count(&self) -> Option<usize> {
+        let mut counts: Vec<Option<usize>> = Vec::with_capacity(self.len());
+        for node in 0..self.len() {
+            let predecessors = self.incoming(node);
+            let count = if predecessors.is_empty() {
+                Some(1)
+            } else {
+                predecessors
+                    .iter()
+                    .try_fold(0usize, |sum, node| sum.checked_add(counts[*node]?))
+            };
+            counts.push(count);
+        }
+
+        self.ends
+            .iter()
+            .try_fold(0usize, |sum, node| sum.checked_add(counts[*node]?))
+    }
+
+    // The optimal paths one at a time, from a start to a target, so there can be any number.
+    pub fn paths(&self) -> Paths<'_, S, I> {
+        Paths {
+            dag: self,
+            ends: self.ends.iter(),
+            stack: Vec::new(),
+        }
+    }
+}
+
+pub struct Paths<'a, S, I> {
+    dag: &'a Dag<'a, S, I>,
+    ends: std::slice::Iter<'a, usize>,
+    // The path so far, from a target backwards, with how many predecessors each node has tried.
+    stack: Vec<(usize, usize)>,
+}
+
+impl<S, I> Iterator for Paths<'_, S, I>
+where
+    S: Copy,
+    I: Indexer<S>,
+{
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Vec<S>> {
+        loop {
+            let Some((node, tried)) = self.stack.last_mut() else {
+                let end = self.ends.next()?;
+                self.stack.push((*end, 0));
+                continue;
+            };
+
+            let predecessors = self.dag.incoming(*node);
+            if predecessors.is_empty() {
+                let path = self
+                    .stack
+                    .iter()
+                    .rev()
+                    .map(|(node, _)| self.dag.state(*node))
+                    .collect();
+                self.stack.pop();
+                return Some(path);
+            }
+
+            match predecessors.get(*tried) {
+                Some(previous) => {
+                    *tried += 1;
+                    self.stack.push((*previous, 0));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+// Yen's algorithm: the `k` cheapest loopless paths from `start` to a goal, cheapest first, each
+// with its cost. Every path after the first leaves an earlier one at some state, so each state of
+// the last path found is searched from again, with the steps earlier paths took from there and
+// the states before it ruled out. The indexer is made afresh for each of those searches.
+pub fn k_shortest<S, I, N>(
+    start: S,
+    mut indexer: impl FnMut() -> I,
+    mut neighbours: impl FnMut(&S) -> N,
+    mut goal: impl FnMut(&S) -> bool,
+    k: usize,
+) -> Vec<(usize, Vec<S>)>
+where
+    S: Copy + Hash + Eq,
+    I: Indexer<S>,
+    N: IntoIterator,
+    N::Item: Into<Option<(S, usize)>>,
+{
+    // Paths are kept with the cost of reaching each of their states.
+    let walk = |search: Search<S, I>| -> Option<Vec<(S, usize)>> {
+        let path = search.path(search.goal()?)?;
+        Some(
+            path.into_iter()
+                .map(|state| (state, search.cost(&state).unwrap()))
+                .collect(),
+        )
+    };
+
+    let mut accepted = Vec::new();
+    let mut candidates: Vec<Vec<(S, usize)>> = Vec::new();
+    if k > 0 {
+        let search = dijkstra([start], indexer(), &mut neighbours, &mut goal);
+        accepted.extend(walk(search));
+    }
+
+    while !accepted.is_empty() && accepted.len() < k {
+        let last = accepted.last().unwrap();
+        for spur in 0..last.len() - 1 {
+            let (spur_state, spur_cost) = last[spur];
+            let root = &last[..=spur];
+            let taken: HashSet<S> = accepted
+                .iter()
+                .filter(|path| path.len() > spur + 1 && path[..=spur] == *root)
+                .map(|path| path[spur + 1].0)
+                .collect();
+            let before: HashSet<S> = root[..spur].iter().map(|(state, _)| *state).collect();
+
+            let (taken, before, neighbours) = (&taken, &before, &mut neighbours);
+            let search = dijkstra(
+                [spur_state],
+                indexer(),
+                move |state: &S| {
+                    let from_spur = *state == spur_state;
+                    neighbours(state)
+                        .into_iter()
+                        .filter_map(Into::<Option<(S, usize)>>::into)
+                        .filter(move |(next, _)| {
+                            !(before.contains(next) || from_spur && taken.contains(next))
+                        })
+                },
+                &mut goal,
+            );
+
+            let Some(branch) = walk(search) else {
+                continue;
+            };
+            let mut path = root[..spur].to_vec();
+            path.extend(
+                branch
+                    .into_iter()
+                    .map(|(state, cost)| (state, cost + spur_cost)),
+            );
+            if !candidates.contains(&path) && !accepted.contains(&path) {
+                candidates.push(path);
+            }
+        }
+
+        // Ties go to the candidate found first.
+        let Some(best) = candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, path)| path.last().unwrap().1)
+            .map(|(index, _)| index)
+        else {
+            break;
+        };
+        accepted.push(candidates.remove(best));
+    }
+
+    accepted
+        .into_iter()
+        .map(|path| {
+            let cost = path.last().unwrap().1;
+            (cost, path.into_iter().map(|(state, _)| state).collect())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod paths_tests {
+    use super::*;
+    use crate::{
+        grid::Grid,
+        points::point::Point,
+        search::{bfs, indexer::Hashed},
+    };
+
+    #[test]
+    fn around_a_pillar() {
+        let map = Grid::parse("#####\n#...#\n#.#.#\n#...#\n#####\n", |byte, _| {
+            Some(byte == b'.')
+        })
+        .unwrap();
+        let neighbours = |point: &Point| {
+            map.adjacent_four(*point)
+                .filter(|next| map[*next])
+                .map(|next| (next, 1))
+        };
+        let (start, end) = (Point::new(1, 1), Point::new(3, 3));
+        let search = dijkstra([start], map.point_indexer(), neighbours, |&point| {
+            point == end
+        });
+
+        // Steps go both ways, so undoing them is taking them again.
+        let dag = search.dag([end], neighbours);
+        assert_eq!(dag.len(), 8);
+        assert!(!dag.contains(&Point::new(2, 2)));
+        assert_eq!(dag.path_count(), Some(2));
+        assert_eq!(dag.states().next(), Some(start));
+        assert_eq!(dag.predecessors(&end).count(), 2);
+
+        let paths: Vec<Vec<Point>> = dag.paths().collect();
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().all(|path| path.len() == 5 && path[0] == start));
+        assert_ne!(paths[0], paths[1]);
+    }
+
+    #[test]
+    fn counts_overflow() {
+        // Two ways into every level, so 2^(level - 1) paths lead to each state past the first.
+        let neighbours = |&(level, _): &(usize, bool)| {
+            [false, true].map(|side| (level < 70).then_some(((level + 1, side), 1)))
+        };
+        let search = bfs(
+            [(0, false)],
+            Hashed::default(),
+            |state| neighbours(state).map(|next| next.map(|(next, _)| next)),
+            |_| false,
+        );
+        let previous = |&(level, _): &(usize, bool)| {
+            [false, true].map(|side| (level > 0).then(|| ((level - 1, side), 1)))
+        };
+        assert_eq!(search.dag([(10, true)], previous).path_count(), Some(512));
+        assert_eq!(
+            search.dag([(64, true)], previous).path_count(),
+            Some(1 << 63)
+        );
+        assert_eq!(search.dag([(65, true)], previous).path_count(), None);
+        assert_eq!(
+            search
+                .dag([(3, true), (3, false)], previous)
+                .paths()
+                .count(),
+            8
+        );
+    }
+
+    #[test]
+    fn yen() {
+        let edges = [
+            ('C', 'D', 3),
+            ('C', 'E', 2),
+            ('D', 'F', 4),
+            ('E', 'D', 1),
+            ('E', 'F', 2),
+            ('E', 'G', 3),
+            ('F', 'G', 2),
+            ('F', 'H', 1),
+            ('G', 'H', 2),
+        ];
+        let paths = k_shortest(
+            'C',
+            Hashed::default,
+            |&from| {
+                edges
+                    .iter()
+                    .filter(move |edge| edge.0 == from)
+                    .map(|edge| (edge.1, edge.2))
+            },
+            |&state| state == 'H',
+            3,
+        );
+        let paths: Vec<(usize, String)> = paths
+            .into_iter()
+            .map(|(cost, path)| (cost, path.into_iter().collect()))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                (5, "CEFH".to_string()),
+                (7, "CEGH".to_string()),
+                (8, "CDFH".to_string())
+            ]
+        );
+    }
+}